@@ -0,0 +1,207 @@
+use std::{cmp::Reverse, path::PathBuf, process};
+
+use cli::{CommonArgs, Parser};
+use dictionary::WordBank;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct LetterCounts {
+    counts: [u8; 26],
+    mask: u32,
+}
+
+impl LetterCounts {
+    fn new(word: &str) -> Option<Self> {
+        word.chars()
+            .try_fold(LetterCounts::default(), |mut letters, c| match c {
+                'a'..='z' => {
+                    let idx = c as usize - 'a' as usize;
+                    letters.counts[idx] = letters.counts[idx].saturating_add(1);
+                    letters.mask |= 1 << idx;
+                    Some(letters)
+                }
+                _ => None,
+            })
+    }
+
+    fn contains(&self, other: &LetterCounts) -> bool {
+        other.mask & !self.mask == 0
+            && self
+                .counts
+                .iter()
+                .zip(other.counts.iter())
+                .all(|(available, needed)| needed <= available)
+    }
+}
+
+fn find_words<'a>(letters: &str, words: impl IntoIterator<Item = &'a str>) -> Vec<&'a str> {
+    let Some(letters) = LetterCounts::new(&letters.trim().to_lowercase()) else {
+        return Vec::new();
+    };
+
+    let mut found: Vec<&str> = words
+        .into_iter()
+        .filter(|word| LetterCounts::new(word).is_some_and(|word| letters.contains(&word)))
+        .collect();
+    found.sort_by_key(|word| (Reverse(word.len()), *word));
+    found.dedup();
+    found
+}
+
+#[derive(Parser, Debug, Default, PartialEq, Eq)]
+struct Args {
+    #[command(flatten)]
+    common: CommonArgs,
+
+    /// Letters to build words from, each usable as many times as it is given
+    letters: Option<String>,
+
+    /// Word list to search instead of the bundled word bank
+    #[arg(long)]
+    words: Option<PathBuf>,
+
+    /// Shortest word to print
+    #[arg(long, default_value_t = 2)]
+    min_len: usize,
+}
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<(), String> {
+    let args = Args::parse();
+    args.common.init_tracing();
+
+    let word_bank = match &args.words {
+        Some(path) => dictionary::load_from_file(path)
+            .map_err(|err| format!("Could not read word file {}: {}", path.display(), err))?,
+        None => WordBank::parse(include_str!("../../spellingbee/src/word_bank.txt")),
+    }
+    .with_length_between(args.min_len, None);
+    let letters = match args.letters {
+        Some(letters) => letters,
+        None => args
+            .common
+            .read_input()
+            .map_err(|err| format!("Could not read letters: {}", err))?
+            .ok_or("Missing letters")?,
+    };
+
+    let found = find_words(&letters, word_bank.iter());
+    if args.common.json {
+        println!("{}", serde_json::json!({ "words": found }));
+    } else if args.common.quiet {
+        found.iter().for_each(|word| println!("{}", word));
+    } else {
+        println!("Words: {}", found.len());
+        for chunk in found.chunk_by(|a, b| a.len() == b.len()) {
+            println!("{}: {}", chunk[0].len(), chunk.join(", "));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod letters {
+        use super::*;
+
+        #[test]
+        fn test_counts() {
+            let letters = LetterCounts::new("ebb").unwrap();
+
+            assert_eq!(letters.counts[0], 0);
+            assert_eq!(letters.counts[1], 2);
+            assert_eq!(letters.counts[4], 1);
+            assert_eq!(letters.mask, 0b10010);
+        }
+
+        #[test]
+        fn test_invalid_letters() {
+            assert_eq!(LetterCounts::new("don't"), None);
+            assert_eq!(LetterCounts::new("Tea"), None);
+        }
+
+        #[test]
+        fn test_contains() {
+            let letters = LetterCounts::new("listen").unwrap();
+
+            assert!(letters.contains(&LetterCounts::new("silent").unwrap()));
+            assert!(letters.contains(&LetterCounts::new("nest").unwrap()));
+            assert!(!letters.contains(&LetterCounts::new("lists").unwrap()));
+            assert!(!letters.contains(&LetterCounts::new("tinsels").unwrap()));
+        }
+
+        #[test]
+        fn test_repeated_letters() {
+            let letters = LetterCounts::new("balloon").unwrap();
+
+            assert!(letters.contains(&LetterCounts::new("ball").unwrap()));
+            assert!(letters.contains(&LetterCounts::new("loon").unwrap()));
+            assert!(!letters.contains(&LetterCounts::new("lull").unwrap()));
+            assert!(!letters.contains(&LetterCounts::new("booboo").unwrap()));
+        }
+    }
+
+    mod find {
+        use super::*;
+
+        const WORDS: [&str; 8] = [
+            "ball", "balloon", "loon", "lob", "all", "lull", "bell", "ab",
+        ];
+
+        #[test]
+        fn test_sorted_by_length() {
+            assert_eq!(
+                find_words("balloon", WORDS),
+                vec!["balloon", "ball", "loon", "all", "lob", "ab"]
+            );
+        }
+
+        #[test]
+        fn test_letters_used_once() {
+            assert_eq!(find_words("bal", WORDS), vec!["ab"]);
+            assert_eq!(find_words("ball", WORDS), vec!["ball", "all", "ab"]);
+        }
+
+        #[test]
+        fn test_input_normalized() {
+            assert_eq!(find_words(" BALL\n", WORDS), vec!["ball", "all", "ab"]);
+            assert!(find_words("b4ll", WORDS).is_empty());
+        }
+    }
+
+    mod args {
+        use super::*;
+
+        fn parse(args: &[&str]) -> Result<Args, clap::Error> {
+            Args::try_parse_from(["anagram"].iter().chain(args))
+        }
+
+        #[test]
+        fn test_defaults() {
+            assert_eq!(
+                parse(&[]).unwrap(),
+                Args {
+                    min_len: 2,
+                    ..Args::default()
+                }
+            );
+        }
+
+        #[test]
+        fn test_letters() {
+            let args = parse(&["balloon", "--min-len", "3", "--quiet"]).unwrap();
+
+            assert_eq!(args.letters, Some("balloon".to_owned()));
+            assert_eq!(args.min_len, 3);
+            assert!(args.common.quiet);
+        }
+    }
+}
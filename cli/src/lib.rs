@@ -0,0 +1,105 @@
+use std::{fs, io, path::PathBuf};
+
+pub use clap::Parser;
+use tracing::level_filters::LevelFilter;
+
+#[derive(clap::Args, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CommonArgs {
+    /// Read the puzzle input from a file instead of the default source
+    #[arg(long)]
+    pub input: Option<PathBuf>,
+
+    /// Print the result as JSON
+    #[arg(long)]
+    pub json: bool,
+
+    /// Print only the result, without progress or diagnostics
+    #[arg(short, long)]
+    pub quiet: bool,
+
+    /// Log solver progress to stderr
+    #[arg(short, long, conflicts_with = "quiet")]
+    pub verbose: bool,
+}
+
+impl CommonArgs {
+    pub fn read_input(&self) -> io::Result<Option<String>> {
+        self.input.as_ref().map(fs::read_to_string).transpose()
+    }
+
+    pub fn log_level(&self) -> LevelFilter {
+        if self.verbose {
+            LevelFilter::DEBUG
+        } else {
+            LevelFilter::WARN
+        }
+    }
+
+    pub fn init_tracing(&self) {
+        tracing_subscriber::fmt()
+            .with_max_level(self.log_level())
+            .with_writer(io::stderr)
+            .init();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Parser, Debug, PartialEq, Eq)]
+    struct Args {
+        #[command(flatten)]
+        common: CommonArgs,
+    }
+
+    fn parse(args: &[&str]) -> Result<CommonArgs, clap::Error> {
+        Args::try_parse_from(["puzzle"].iter().chain(args)).map(|args| args.common)
+    }
+
+    #[test]
+    fn test_defaults() {
+        let args = parse(&[]).unwrap();
+
+        assert_eq!(args, CommonArgs::default());
+        assert_eq!(args.log_level(), LevelFilter::WARN);
+    }
+
+    #[test]
+    fn test_flags() {
+        assert_eq!(
+            parse(&["--input", "puzzle.txt", "--json", "-q"]).unwrap(),
+            CommonArgs {
+                input: Some("puzzle.txt".into()),
+                json: true,
+                quiet: true,
+                verbose: false,
+            }
+        );
+        assert_eq!(
+            parse(&["--verbose"]).unwrap().log_level(),
+            LevelFilter::DEBUG
+        );
+        assert_eq!(parse(&["-v"]).unwrap().log_level(), LevelFilter::DEBUG);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(parse(&["--input"]).is_err());
+        assert!(parse(&["--bogus"]).is_err());
+        assert!(parse(&["--quiet", "--verbose"]).is_err());
+    }
+
+    #[test]
+    fn test_read_input() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml");
+        let args = parse(&["--input", path]).unwrap();
+
+        assert!(args
+            .read_input()
+            .unwrap()
+            .unwrap()
+            .contains("name = \"cli\""));
+        assert_eq!(CommonArgs::default().read_input().unwrap(), None);
+    }
+}
@@ -0,0 +1,172 @@
+//! Word banks shared by the puzzle solvers.
+//!
+//! ```
+//! use dictionary::WordBank;
+//!
+//! let bank = WordBank::parse("crane\nbee\nslate\npuzzle\n").with_length(5);
+//! assert_eq!(bank.words(), ["crane", "slate"]);
+//! ```
+
+use std::{fs, io, path::Path};
+
+#[cfg(feature = "db")]
+use entity::prelude::*;
+#[cfg(feature = "db")]
+use sea_orm::{ConnectionTrait, DbErr, EntityTrait};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WordBank {
+    words: Vec<String>,
+}
+
+impl WordBank {
+    pub fn new<T: Into<String>>(words: impl IntoIterator<Item = T>) -> Self {
+        WordBank {
+            words: words.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    pub fn parse(text: &str) -> Self {
+        Self::new(text.lines().map(str::trim).filter(|word| !word.is_empty()))
+    }
+
+    pub fn with_length(self, length: usize) -> Self {
+        self.with_length_between(length, Some(length))
+    }
+
+    pub fn with_length_between(mut self, min: usize, max: Option<usize>) -> Self {
+        self.words.retain(|word| {
+            let length = word.chars().count();
+            length >= min && max.is_none_or(|max| length <= max)
+        });
+        self
+    }
+
+    pub fn with_charset(mut self, charset: &str) -> Self {
+        self.words
+            .retain(|word| word.chars().all(|c| charset.contains(c)));
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.words.iter().map(String::as_str)
+    }
+
+    pub fn into_words(self) -> Vec<String> {
+        self.words
+    }
+}
+
+pub fn load_from_file(path: impl AsRef<Path>) -> io::Result<WordBank> {
+    Ok(WordBank::parse(&fs::read_to_string(path)?))
+}
+
+#[cfg(feature = "db")]
+pub async fn load_from_db<C: ConnectionTrait>(
+    db: &C,
+    length: Option<usize>,
+) -> Result<WordBank, DbErr> {
+    let words = match length {
+        Some(length) => Word::find_by_length(length as i32).all(db).await?,
+        None => Word::find().all(db).await?,
+    };
+
+    Ok(WordBank::new(words.into_iter().map(|word| word.text)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod filters {
+        use super::*;
+
+        fn bank() -> WordBank {
+            WordBank::parse("crane\n bee \n\nslate\npuzzle\ncaf\u{e9}s\n")
+        }
+
+        #[test]
+        fn test_parse() {
+            assert_eq!(
+                bank().words(),
+                ["crane", "bee", "slate", "puzzle", "caf\u{e9}s"]
+            );
+        }
+
+        #[test]
+        fn test_length() {
+            assert_eq!(
+                bank().with_length(5).words(),
+                ["crane", "slate", "caf\u{e9}s"]
+            );
+            assert!(bank().with_length(2).is_empty());
+        }
+
+        #[test]
+        fn test_length_between() {
+            assert_eq!(
+                bank().with_length_between(4, None).words(),
+                ["crane", "slate", "puzzle", "caf\u{e9}s"]
+            );
+            assert_eq!(bank().with_length_between(3, Some(5)).len(), 4);
+        }
+
+        #[test]
+        fn test_charset() {
+            let bank = bank().with_charset("abcdefghijklmnopqrstuvwxyz");
+            assert_eq!(bank.words(), ["crane", "bee", "slate", "puzzle"]);
+
+            let bank = bank.with_charset("aceinrst");
+            assert_eq!(bank.words(), ["crane"]);
+        }
+    }
+
+    mod loading {
+        use super::*;
+
+        #[test]
+        fn test_load_from_file() {
+            let path = concat!(env!("CARGO_MANIFEST_DIR"), "/../wordle/src/word_bank.txt");
+            let bank = load_from_file(path).unwrap();
+
+            assert_eq!(bank.len(), bank.clone().with_length(5).len());
+            assert!(bank.iter().any(|word| word == "crane"));
+            assert!(load_from_file("/nonexistent/words.txt").is_err());
+        }
+
+        #[cfg(feature = "db")]
+        #[tokio::test]
+        async fn test_load_from_db() {
+            use entity::word::ActiveModel;
+            use migration::{Migrator, MigratorTrait};
+            use sea_orm::{ActiveValue::Set, Database};
+
+            let db = Database::connect("sqlite::memory:").await.unwrap();
+            Migrator::up(&db, None).await.unwrap();
+            let words = ["crane", "bee", "slate"].map(|word| ActiveModel {
+                text: Set(word.to_owned()),
+                length: Set(word.len() as i32),
+                ..Default::default()
+            });
+            Word::insert_many(words).exec(&db).await.unwrap();
+
+            assert_eq!(load_from_db(&db, None).await.unwrap().len(), 3);
+            assert_eq!(
+                load_from_db(&db, Some(5)).await.unwrap().words(),
+                ["crane", "slate"]
+            );
+        }
+    }
+}
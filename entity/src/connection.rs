@@ -1,5 +1,153 @@
-use sea_orm::{Database, DatabaseConnection, DbErr};
+use std::{env, future::Future, time::Duration};
+
+use sea_orm::{ConnectionTrait, Database, DatabaseConnection, DbErr, Statement};
+
+fn database_url() -> String {
+    env::var("DATABASE_URL").unwrap_or_else(|_| dotenv!("DATABASE_URL").to_owned())
+}
 
 pub async fn get_connection() -> Result<DatabaseConnection, DbErr> {
-    Database::connect(dotenv!("DATABASE_URL")).await
+    get_connection_from(&database_url()).await
+}
+
+pub async fn get_connection_from(url: &str) -> Result<DatabaseConnection, DbErr> {
+    Database::connect(url).await
+}
+
+pub async fn get_connection_retry(
+    max_attempts: u32,
+    base_delay: Duration,
+) -> Result<DatabaseConnection, DbErr> {
+    get_connection_from_retry(&database_url(), max_attempts, base_delay).await
+}
+
+pub async fn get_connection_from_retry(
+    url: &str,
+    max_attempts: u32,
+    base_delay: Duration,
+) -> Result<DatabaseConnection, DbErr> {
+    retry(max_attempts, base_delay, || get_connection_from(url)).await
+}
+
+pub async fn ping(db: &DatabaseConnection) -> Result<(), DbErr> {
+    db.execute(Statement::from_string(
+        db.get_database_backend(),
+        "SELECT 1",
+    ))
+    .await
+    .map(|_| ())
+}
+
+async fn retry<T, F, Fut>(
+    max_attempts: u32,
+    base_delay: Duration,
+    mut attempt: F,
+) -> Result<T, DbErr>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, DbErr>>,
+{
+    let mut delay = base_delay;
+    let mut attempts = 1;
+    loop {
+        match attempt().await {
+            Ok(value) => return Ok(value),
+            Err(err) if attempts >= max_attempts => return Err(err),
+            Err(_) => {
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+                attempts += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::RuntimeErr;
+
+    #[tokio::test]
+    async fn test_ping() {
+        let db = get_connection_from("sqlite::memory:").await.unwrap();
+
+        assert_eq!(ping(&db).await, Ok(()));
+    }
+
+    #[tokio::test]
+    async fn test_ping_closed() {
+        let db = get_connection_from("sqlite::memory:").await.unwrap();
+        let closed = db.clone();
+        db.close().await.unwrap();
+
+        assert!(ping(&closed).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_bad_url() {
+        let err = get_connection_from("not a database url").await.unwrap_err();
+
+        assert!(matches!(err, DbErr::Conn(RuntimeErr::Internal(_))));
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_driver() {
+        let err = get_connection_from("mysql://localhost/words")
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, DbErr::Conn(RuntimeErr::Internal(_))));
+    }
+
+    #[tokio::test]
+    async fn test_retry_attempts() {
+        let mut attempts = 0;
+        let result: Result<(), DbErr> = retry(3, Duration::from_millis(1), || {
+            attempts += 1;
+            async { Err(DbErr::Custom("unreachable".to_owned())) }
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(attempts, 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_succeeds() {
+        let mut attempts = 0;
+        let result = retry(5, Duration::from_millis(1), || {
+            attempts += 1;
+            let attempt = attempts;
+            async move {
+                if attempt < 2 {
+                    Err(DbErr::Custom("not ready".to_owned()))
+                } else {
+                    Ok(attempt)
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(result, Ok(2));
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_database() {
+        let url = "sqlite:///nonexistent/directory/db.sqlite";
+        let err = get_connection_from_retry(url, 2, Duration::from_millis(1))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, DbErr::Conn(_)));
+
+        let mut attempts = 0;
+        let err = retry(3, Duration::from_millis(1), || {
+            attempts += 1;
+            get_connection_from(url)
+        })
+        .await
+        .unwrap_err();
+
+        assert!(matches!(err, DbErr::Conn(_)));
+        assert_eq!(attempts, 3);
+    }
 }
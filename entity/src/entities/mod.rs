@@ -2,4 +2,6 @@
 
 pub mod prelude;
 
+pub mod game;
+pub mod guess;
 pub mod word;
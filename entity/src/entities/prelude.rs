@@ -1,3 +1,5 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.0
 
+pub use super::game::Entity as Game;
+pub use super::guess::Entity as Guess;
 pub use super::word::Entity as Word;
@@ -0,0 +1,82 @@
+use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveValue::Set, QueryOrder};
+
+use crate::{game, guess};
+
+impl game::Entity {
+    pub async fn create<C: ConnectionTrait>(db: &C) -> Result<game::Model, DbErr> {
+        game::ActiveModel {
+            created_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        }
+        .insert(db)
+        .await
+    }
+}
+
+impl game::Model {
+    pub async fn add_guess<C: ConnectionTrait>(
+        &self,
+        db: &C,
+        word: &str,
+        clues: &str,
+    ) -> Result<guess::Model, DbErr> {
+        guess::ActiveModel {
+            game_id: Set(self.id),
+            word: Set(word.to_owned()),
+            clues: Set(clues.to_owned()),
+            created_at: Set(chrono::Utc::now()),
+            ..Default::default()
+        }
+        .insert(db)
+        .await
+    }
+
+    // Guesses in the order they were made
+    pub async fn history<C: ConnectionTrait>(&self, db: &C) -> Result<Vec<guess::Model>, DbErr> {
+        self.find_related(guess::Entity)
+            .order_by_asc(guess::Column::Id)
+            .all(db)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use migration::{Migrator, MigratorTrait};
+    use sea_orm::Database;
+
+    #[tokio::test]
+    async fn test_history() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+
+        let game = game::Entity::create(&db).await.unwrap();
+        let other = game::Entity::create(&db).await.unwrap();
+        game.add_guess(&db, "serai", "bybbg").await.unwrap();
+        other.add_guess(&db, "crane", "bbbbb").await.unwrap();
+        game.add_guess(&db, "there", "bybyg").await.unwrap();
+
+        let game = game::Entity::find_by_id(game.id)
+            .one(&db)
+            .await
+            .unwrap()
+            .unwrap();
+        let history: Vec<(String, String)> = game
+            .history(&db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|guess| (guess.word, guess.clues))
+            .collect();
+
+        assert_eq!(
+            history,
+            vec![
+                ("serai".to_owned(), "bybbg".to_owned()),
+                ("there".to_owned(), "bybyg".to_owned()),
+            ]
+        );
+    }
+}
@@ -3,6 +3,9 @@ extern crate dotenv_codegen;
 
 mod connection;
 mod entities;
+mod game_history;
+mod word_queries;
 
 pub use connection::*;
 pub use entities::*;
+pub use word_queries::*;
@@ -0,0 +1,86 @@
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::OnConflict;
+
+use crate::word::{ActiveModel, Column, Entity};
+
+impl Entity {
+    pub fn find_by_length(length: i32) -> Select<Entity> {
+        Self::find().filter(Column::Length.eq(length))
+    }
+}
+
+pub async fn insert_words_ignoring_conflicts<C: ConnectionTrait>(
+    db: &C,
+    batch: Vec<ActiveModel>,
+) -> Result<u64, DbErr> {
+    if batch.is_empty() {
+        return Ok(0);
+    }
+
+    Entity::insert_many(batch)
+        .on_conflict(OnConflict::column(Column::Text).do_nothing().to_owned())
+        .exec_without_returning(db)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use migration::{Migrator, MigratorTrait};
+    use sea_orm::{ActiveValue::Set, Database};
+
+    fn models(words: &[&str]) -> Vec<ActiveModel> {
+        words
+            .iter()
+            .map(|word| ActiveModel {
+                text: Set((*word).to_owned()),
+                length: Set(word.len() as i32),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_find_by_length() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+
+        Entity::insert_many(models(&["crane", "bee", "slate", "puzzle"]))
+            .exec(&db)
+            .await
+            .unwrap();
+
+        let words: Vec<String> = Entity::find_by_length(5)
+            .all(&db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|word| word.text)
+            .collect();
+
+        assert_eq!(words, vec!["crane", "slate"]);
+    }
+
+    #[tokio::test]
+    async fn test_insert_ignoring_conflicts() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+
+        let first = models(&["crane", "slate", "bee"]);
+        let second = models(&["slate", "puzzle", "crane", "hive"]);
+        for _ in 0..2 {
+            insert_words_ignoring_conflicts(&db, first.clone())
+                .await
+                .unwrap();
+            insert_words_ignoring_conflicts(&db, second.clone())
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(Entity::find().count(&db).await.unwrap(), 5);
+        assert!(Entity::insert_many(models(&["bee"]))
+            .exec(&db)
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,55 @@
+use std::io;
+
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum PuzzleError {
+    #[error("Could not parse puzzle: {reason}")]
+    Parse { reason: String },
+
+    #[error("Puzzle has no solution")]
+    Unsolvable,
+
+    #[error("Cell ({x}, {y}) is outside the grid")]
+    OutOfBounds { x: usize, y: usize },
+
+    #[error(transparent)]
+    Io(#[from] io::Error),
+}
+
+impl PuzzleError {
+    pub fn parse(reason: impl Into<String>) -> Self {
+        PuzzleError::Parse {
+            reason: reason.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            PuzzleError::parse("Wrong number of rows").to_string(),
+            "Could not parse puzzle: Wrong number of rows"
+        );
+        assert_eq!(
+            PuzzleError::Unsolvable.to_string(),
+            "Puzzle has no solution"
+        );
+        assert_eq!(
+            PuzzleError::OutOfBounds { x: 9, y: 0 }.to_string(),
+            "Cell (9, 0) is outside the grid"
+        );
+    }
+
+    #[test]
+    fn test_from_io() {
+        let err: PuzzleError = io::Error::new(io::ErrorKind::NotFound, "missing").into();
+
+        assert!(matches!(err, PuzzleError::Io(_)));
+        assert_eq!(err.to_string(), "missing");
+    }
+}
@@ -1,12 +1,20 @@
 pub use sea_orm_migration::prelude::*;
 
 mod m20220101_000001_create_table;
+mod m20241101_000001_add_word_length;
+mod m20241102_000001_create_game_tables;
+mod m20241103_000001_unique_word_text;
 
 pub struct Migrator;
 
 #[async_trait::async_trait]
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(m20220101_000001_create_table::Migration)]
+        vec![
+            Box::new(m20220101_000001_create_table::Migration),
+            Box::new(m20241101_000001_add_word_length::Migration),
+            Box::new(m20241102_000001_create_game_tables::Migration),
+            Box::new(m20241103_000001_unique_word_text::Migration),
+        ]
     }
 }
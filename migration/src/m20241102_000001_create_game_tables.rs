@@ -0,0 +1,68 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Game::Table)
+                    .if_not_exists()
+                    .col(pk_auto(Game::Id))
+                    .col(timestamp_with_time_zone(Game::CreatedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(Guess::Table)
+                    .if_not_exists()
+                    .col(pk_auto(Guess::Id))
+                    .col(integer(Guess::GameId))
+                    .col(string(Guess::Word))
+                    .col(string(Guess::Clues))
+                    .col(timestamp_with_time_zone(Guess::CreatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-guess-game_id")
+                            .from(Guess::Table, Guess::GameId)
+                            .to(Game::Table, Game::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Guess::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Game::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Game {
+    Table,
+    Id,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Guess {
+    Table,
+    Id,
+    GameId,
+    Word,
+    Clues,
+    CreatedAt,
+}
@@ -1,49 +1,777 @@
-#![feature(iter_array_chunks)]
 extern crate entity;
 extern crate indicatif;
 
-use entity::{prelude::*, word::ActiveModel};
+use entity::{insert_words_ignoring_conflicts, prelude::*, word::ActiveModel};
 use indicatif::ProgressBar;
-use sea_orm::{DatabaseConnection, EntityTrait, Set};
+use sea_orm::{
+    ConnectionTrait, DatabaseConnection, DbErr, EntityTrait, PaginatorTrait, Set, TransactionTrait,
+};
+use std::{
+    collections::HashSet, env, error::Error, fmt::Display, future::Future, ops::Range,
+    path::PathBuf, time::Duration,
+};
+use tokio::io::AsyncReadExt;
 
 const CHUNK_SIZE: usize = 5000;
+// Gives a database that is still starting up a few seconds before giving up
+const CONNECT_ATTEMPTS: u32 = 5;
+const CONNECT_DELAY: Duration = Duration::from_millis(500);
+const WORDS_URL: &str =
+    "https://raw.githubusercontent.com/dwyl/english-words/refs/heads/master/words_alpha.txt";
 
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let words: Vec<entity::word::ActiveModel> = reqwest::get(
-        "https://raw.githubusercontent.com/dwyl/english-words/refs/heads/master/words_alpha.txt",
-    )
-    .await?
-    .text()
-    .await?
-    .lines()
-    .map(|line| ActiveModel {
-        text: Set(line.to_owned()),
-        ..Default::default()
-    })
-    .collect();
+#[derive(Debug, PartialEq, Eq)]
+enum Source {
+    File(PathBuf),
+    Url(String),
+}
+
+impl Source {
+    async fn open(&self) -> Result<ByteStream, Box<dyn Error>> {
+        match self {
+            Source::File(path) => Ok(ByteStream::File(tokio::fs::File::open(path).await?)),
+            Source::Url(url) => Ok(ByteStream::Url(
+                reqwest::get(url).await?.error_for_status()?,
+            )),
+        }
+    }
+}
 
-    let db: DatabaseConnection = entity::get_connection().await?;
+enum ByteStream {
+    File(tokio::fs::File),
+    Url(reqwest::Response),
+}
 
-    let remainder = words.len() - (words.len() % CHUNK_SIZE);
-    let remainder: Vec<ActiveModel> = words.iter().skip(remainder).cloned().collect();
+impl ByteStream {
+    async fn next(&mut self) -> Result<Option<Vec<u8>>, Box<dyn Error>> {
+        match self {
+            ByteStream::File(file) => {
+                let mut chunk = vec![0; 64 * 1024];
+                let n = file.read(&mut chunk).await?;
+                chunk.truncate(n);
+                Ok((n > 0).then_some(chunk))
+            }
+            ByteStream::Url(response) => Ok(response.chunk().await?.map(|chunk| chunk.to_vec())),
+        }
+    }
+}
 
-    println!("Processing...");
-    let pb = ProgressBar::new(words.len() as u64);
-    for batch in words.into_iter().array_chunks::<CHUNK_SIZE>() {
-        pb.inc(CHUNK_SIZE as u64);
-        Word::insert_many(batch).exec(&db).await?;
+// Splits a byte stream into normalized words, skipping the first `skip` of them
+#[derive(Default)]
+struct WordReader {
+    partial: Vec<u8>,
+    words: Vec<String>,
+    skip: usize,
+}
+
+impl WordReader {
+    fn push(&mut self, chunk: &[u8]) {
+        let mut lines = chunk.split(|&b| b == b'\n');
+        let last = lines.next_back().unwrap_or_default();
+        for line in lines {
+            if self.partial.is_empty() {
+                self.add_line(line);
+            } else {
+                let mut partial = std::mem::take(&mut self.partial);
+                partial.extend_from_slice(line);
+                self.add_line(&partial);
+            }
+        }
+        self.partial.extend_from_slice(last);
+    }
+
+    fn add_line(&mut self, line: &[u8]) {
+        if let Some(word) = std::str::from_utf8(line).ok().and_then(normalize) {
+            if self.skip > 0 {
+                self.skip -= 1;
+            } else {
+                self.words.push(word);
+            }
+        }
+    }
+
+    // The first `size` words, once that many have been read
+    fn take(&mut self, size: usize) -> Option<Vec<String>> {
+        if self.words.len() < size {
+            return None;
+        }
+        let rest = self.words.split_off(size);
+        Some(std::mem::replace(&mut self.words, rest))
+    }
+
+    fn finish(&mut self) {
+        let partial = std::mem::take(&mut self.partial);
+        self.add_line(&partial);
+    }
+}
+
+trait Batches<T> {
+    async fn next_batch(&mut self) -> Result<Option<Vec<T>>, Box<dyn Error>>;
+}
+
+// Words from a source in batches of `chunk_size`, so only one batch is held at a time.
+// Duplicates are dropped within a batch; the unique index on word text ignores the rest
+struct WordBatches {
+    bytes: Option<ByteStream>,
+    reader: WordReader,
+    chunk_size: usize,
+    duplicates: usize,
+}
+
+impl WordBatches {
+    async fn open(source: &Source, chunk_size: usize, skip: usize) -> Result<Self, Box<dyn Error>> {
+        Ok(WordBatches {
+            bytes: Some(source.open().await?),
+            reader: WordReader {
+                skip,
+                ..Default::default()
+            },
+            chunk_size,
+            duplicates: 0,
+        })
+    }
+}
+
+impl Batches<String> for WordBatches {
+    async fn next_batch(&mut self) -> Result<Option<Vec<String>>, Box<dyn Error>> {
+        let batch = loop {
+            if let Some(batch) = self.reader.take(self.chunk_size) {
+                break batch;
+            }
+            match &mut self.bytes {
+                Some(bytes) => match bytes.next().await? {
+                    Some(chunk) => self.reader.push(&chunk),
+                    None => {
+                        self.bytes = None;
+                        self.reader.finish();
+                    }
+                },
+                None if self.reader.words.is_empty() => return Ok(None),
+                None => break std::mem::take(&mut self.reader.words),
+            }
+        };
+
+        let (batch, duplicates) = dedup(batch);
+        self.duplicates += duplicates;
+        Ok(Some(batch))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Args {
+    chunk_size: usize,
+    keep_going: bool,
+    retries: usize,
+    source: Source,
+    transaction: bool,
+    skip: Option<usize>,
+    resume: bool,
+}
+
+impl Args {
+    fn parse(
+        mut args: impl Iterator<Item = String>,
+        chunk_size: Option<String>,
+    ) -> Result<Self, String> {
+        let mut chunk_size = chunk_size;
+        let mut keep_going = false;
+        let mut retries = 0;
+        let mut source = None;
+        let mut transaction = true;
+        let mut skip = None;
+        let mut resume = false;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--chunk-size" => {
+                    chunk_size = Some(args.next().ok_or("Missing value for --chunk-size")?)
+                }
+                "--keep-going" => keep_going = true,
+                "--no-transaction" => transaction = false,
+                "--resume" => resume = true,
+                "--skip" => {
+                    let value = args.next().ok_or("Missing value for --skip")?;
+                    skip = Some(
+                        value
+                            .parse()
+                            .map_err(|_| format!("Invalid skip count {}", value))?,
+                    );
+                }
+                "--file" => {
+                    let path = args.next().ok_or("Missing value for --file")?;
+                    source = Some(Source::File(path.into()));
+                }
+                "--url" => {
+                    let url = args.next().ok_or("Missing value for --url")?;
+                    source = Some(Source::Url(url));
+                }
+                "--retries" => {
+                    let value = args.next().ok_or("Missing value for --retries")?;
+                    retries = value
+                        .parse()
+                        .map_err(|_| format!("Invalid retry count {}", value))?;
+                }
+                _ => return Err(format!("Unknown argument {}", arg)),
+            }
+        }
+
+        let chunk_size = match chunk_size {
+            Some(chunk_size) => chunk_size
+                .parse()
+                .map_err(|_| format!("Invalid chunk size {}", chunk_size))?,
+            None => CHUNK_SIZE,
+        };
+        if chunk_size == 0 {
+            return Err("Chunk size must be nonzero".to_owned());
+        }
+
+        Ok(Args {
+            chunk_size,
+            keep_going,
+            retries,
+            source: source.unwrap_or_else(|| Source::Url(WORDS_URL.to_owned())),
+            transaction,
+            skip,
+            resume,
+        })
     }
+}
 
-    if !remainder.is_empty() {
-        let size = remainder.len();
-        Word::insert_many(remainder).exec(&db).await?;
-        pb.inc(size as u64);
+fn normalize(line: &str) -> Option<String> {
+    let word = line.trim().to_lowercase();
+    if word.is_empty() || !word.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
     }
 
+    Some(word)
+}
+
+fn models(words: Vec<String>) -> Vec<ActiveModel> {
+    words
+        .into_iter()
+        .map(|word| ActiveModel {
+            length: Set(word.len() as i32),
+            text: Set(word),
+            ..Default::default()
+        })
+        .collect()
+}
+
+#[derive(Debug, Default)]
+struct BatchSummary {
+    succeeded: usize,
+    failed: Vec<Range<usize>>,
+}
+
+async fn insert_batches<T, E, F, Fut>(
+    batches: &mut impl Batches<T>,
+    args: &Args,
+    mut insert: F,
+    mut progress: impl FnMut(u64),
+) -> Result<BatchSummary, Box<dyn Error>>
+where
+    T: Clone,
+    E: Display + Into<Box<dyn Error>>,
+    F: FnMut(Vec<T>) -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+{
+    let mut summary = BatchSummary::default();
+    let mut start = 0;
+    while let Some(batch) = batches.next_batch().await? {
+        let range = start..start + batch.len();
+        start = range.end;
+
+        let mut attempt = 0;
+        let result = loop {
+            match insert(batch.to_vec()).await {
+                Err(err) if attempt < args.retries => {
+                    eprintln!("Batch {:?} failed, retrying: {}", range, err);
+                    attempt += 1;
+                }
+                result => break result,
+            }
+        };
+
+        match result {
+            Ok(()) => {
+                summary.succeeded += 1;
+                progress(batch.len() as u64);
+            }
+            Err(err) if args.keep_going => {
+                eprintln!("Batch {:?} failed: {}", range, err);
+                summary.failed.push(range);
+            }
+            Err(err) => return Err(err.into()),
+        }
+    }
+
+    Ok(summary)
+}
+
+async fn insert_batch<C>(db: &C, batch: Vec<ActiveModel>, transaction: bool) -> Result<(), DbErr>
+where
+    C: ConnectionTrait + TransactionTrait,
+{
+    if !transaction {
+        insert_words_ignoring_conflicts(db, batch).await?;
+        return Ok(());
+    }
+
+    let txn = db.begin().await?;
+    insert_words_ignoring_conflicts(&txn, batch).await?;
+    txn.commit().await
+}
+
+async fn resume_offset<C: ConnectionTrait>(db: &C, args: &Args) -> Result<usize, DbErr> {
+    match args.skip {
+        Some(skip) => Ok(skip),
+        None if args.resume => Ok(Word::find().count(db).await? as usize),
+        None => Ok(0),
+    }
+}
+
+fn dedup(words: Vec<String>) -> (Vec<String>, usize) {
+    let total = words.len();
+    let mut seen: HashSet<String> = HashSet::with_capacity(total);
+    let words: Vec<String> = words
+        .into_iter()
+        .filter(|word| seen.insert(word.clone()))
+        .collect();
+    let duplicates = total - words.len();
+
+    (words, duplicates)
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let args = Args::parse(env::args().skip(1), env::var("CHUNK_SIZE").ok())?;
+
+    let db: DatabaseConnection =
+        entity::get_connection_retry(CONNECT_ATTEMPTS, CONNECT_DELAY).await?;
+    entity::ping(&db)
+        .await
+        .map_err(|err| format!("Database is unavailable: {}", err))?;
+
+    let offset = resume_offset(&db, &args).await?;
+    if offset > 0 {
+        println!("Skipping {} words", offset);
+    }
+    let mut words = WordBatches::open(&args.source, args.chunk_size, offset).await?;
+
+    println!("Processing...");
+    let pb = ProgressBar::no_length();
+    let summary = insert_batches(
+        &mut words,
+        &args,
+        |batch| insert_batch(&db, models(batch), args.transaction),
+        |n| pb.inc(n),
+    )
+    .await?;
+
     pb.finish_and_clear();
 
+    println!("Dropped {} duplicate words", words.duplicates);
+    println!(
+        "Inserted {} batches, {} failed",
+        summary.succeeded,
+        summary.failed.len()
+    );
+    for range in &summary.failed {
+        println!("Failed batch: words {}..{}", range.start, range.end);
+    }
+
     println!("Done!");
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use migration::{Migrator, MigratorTrait};
+    use std::slice::Chunks;
+
+    impl<T: Clone> Batches<T> for Chunks<'_, T> {
+        async fn next_batch(&mut self) -> Result<Option<Vec<T>>, Box<dyn Error>> {
+            Ok(self.next().map(<[T]>::to_vec))
+        }
+    }
+
+    fn fixture() -> Source {
+        Source::File(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/words.txt"))
+    }
+
+    async fn read_batches(chunk_size: usize) -> (Vec<Vec<String>>, usize) {
+        let mut words = WordBatches::open(&fixture(), chunk_size, 0).await.unwrap();
+        let mut batches = Vec::new();
+        while let Some(batch) = words.next_batch().await.unwrap() {
+            batches.push(batch);
+        }
+        (batches, words.duplicates)
+    }
+
+    #[test]
+    fn test_normalize() {
+        let words: Vec<String> = [
+            "apple",
+            " Banana \r",
+            "",
+            "don't",
+            "x-ray",
+            "CHERRY",
+            "caf\u{e9}",
+            "42",
+        ]
+        .into_iter()
+        .filter_map(normalize)
+        .collect();
+
+        assert_eq!(words, vec!["apple", "banana", "cherry"]);
+    }
+
+    #[test]
+    fn test_dedup() {
+        let words: Vec<String> = ["apple", "Apple", "banana", "apple", "cherry", "banana"]
+            .into_iter()
+            .filter_map(normalize)
+            .collect();
+        let (words, duplicates) = dedup(words);
+
+        assert_eq!(words, vec!["apple", "banana", "cherry"]);
+        assert_eq!(duplicates, 3);
+    }
+
+    fn parse(args: &[&str], chunk_size: Option<&str>) -> Result<Args, String> {
+        Args::parse(
+            args.iter().map(|arg| arg.to_string()),
+            chunk_size.map(str::to_owned),
+        )
+    }
+
+    fn args(chunk_size: usize, keep_going: bool, retries: usize) -> Args {
+        Args {
+            chunk_size,
+            keep_going,
+            retries,
+            source: Source::Url(WORDS_URL.to_owned()),
+            transaction: true,
+            skip: None,
+            resume: false,
+        }
+    }
+
+    #[test]
+    fn test_chunk_size() {
+        assert_eq!(parse(&[], None), Ok(args(5000, false, 0)));
+        assert_eq!(parse(&[], Some("100")), Ok(args(100, false, 0)));
+        assert_eq!(
+            parse(&["--chunk-size", "250"], Some("100")),
+            Ok(args(250, false, 0))
+        );
+    }
+
+    #[test]
+    fn test_keep_going() {
+        assert_eq!(
+            parse(&["--keep-going", "--retries", "2"], None),
+            Ok(args(5000, true, 2))
+        );
+        assert!(parse(&["--retries", "-1"], None).is_err());
+    }
+
+    #[test]
+    fn test_source() {
+        assert_eq!(
+            parse(&["--file", "words.txt"], None).unwrap().source,
+            Source::File("words.txt".into())
+        );
+        assert_eq!(
+            parse(&["--url", "http://localhost/words.txt"], None)
+                .unwrap()
+                .source,
+            Source::Url("http://localhost/words.txt".to_owned())
+        );
+        assert!(parse(&["--file"], None).is_err());
+    }
+
+    #[test]
+    fn test_no_transaction() {
+        assert!(parse(&[], None).unwrap().transaction);
+        assert!(!parse(&["--no-transaction"], None).unwrap().transaction);
+    }
+
+    fn model(id: i32, word: &str) -> ActiveModel {
+        ActiveModel {
+            id: Set(id),
+            text: Set(word.to_owned()),
+            length: Set(word.len() as i32),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failed_batch_rolls_back() {
+        let db = sea_orm::Database::connect("sqlite::memory:").await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+
+        insert_batch(&db, vec![model(1, "apple"), model(2, "banana")], true)
+            .await
+            .unwrap();
+
+        let result = insert_batch(&db, vec![model(3, "cherry"), model(1, "damson")], true).await;
+
+        assert!(result.is_err());
+        let words: Vec<String> = Word::find()
+            .all(&db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|word| word.text)
+            .collect();
+        assert_eq!(words, vec!["apple", "banana"]);
+    }
+
+    #[test]
+    fn test_skip() {
+        assert_eq!(parse(&["--skip", "3"], None).unwrap().skip, Some(3));
+        assert!(parse(&["--resume"], None).unwrap().resume);
+        assert!(parse(&["--skip", "many"], None).is_err());
+    }
+
+    async fn seed_fixture(db: &DatabaseConnection, args: &Args) -> Vec<String> {
+        let offset = resume_offset(db, args).await.unwrap();
+        let mut words = WordBatches::open(&fixture(), args.chunk_size, offset)
+            .await
+            .unwrap();
+        insert_batches(
+            &mut words,
+            args,
+            |batch| insert_batch(db, models(batch), args.transaction),
+            |_| {},
+        )
+        .await
+        .unwrap();
+
+        Word::find()
+            .all(db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|word| word.text)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_skip_inserts_remainder() {
+        let db = sea_orm::Database::connect("sqlite::memory:").await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+
+        let args = Args {
+            skip: Some(2),
+            ..args(2, false, 0)
+        };
+
+        // The skipped apple is repeated later in the file
+        assert_eq!(seed_fixture(&db, &args).await, vec!["cherry", "apple"]);
+    }
+
+    #[tokio::test]
+    async fn test_resume_from_count() {
+        let db = sea_orm::Database::connect("sqlite::memory:").await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        insert_batch(&db, vec![model(1, "apple")], true)
+            .await
+            .unwrap();
+
+        let args = Args {
+            resume: true,
+            ..args(2, false, 0)
+        };
+
+        assert_eq!(
+            seed_fixture(&db, &args).await,
+            vec!["apple", "banana", "cherry"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_read_file() {
+        let (batches, duplicates) = read_batches(10).await;
+
+        assert_eq!(batches, vec![vec!["apple", "banana", "cherry"]]);
+        assert_eq!(duplicates, 1);
+    }
+
+    #[test]
+    fn test_streamed_matches_buffered() {
+        let text = include_str!("../../tests/fixtures/words.txt");
+        let buffered: Vec<String> = text.lines().filter_map(normalize).collect();
+
+        for chunk_size in 1..=text.len() {
+            let mut reader = WordReader::default();
+            for chunk in text.as_bytes().chunks(chunk_size) {
+                reader.push(chunk);
+            }
+            reader.finish();
+
+            assert_eq!(reader.words, buffered);
+        }
+    }
+
+    #[test]
+    fn test_streamed_split_character() {
+        let text = "caf\u{e9}\nbanana\napple";
+        let mut reader = WordReader::default();
+        for chunk in text.as_bytes().chunks(4) {
+            reader.push(chunk);
+        }
+        reader.finish();
+
+        assert_eq!(reader.words, vec!["banana", "apple"]);
+    }
+
+    #[test]
+    fn test_invalid_chunk_size() {
+        assert!(parse(&["--chunk-size", "0"], None).is_err());
+        assert!(parse(&[], Some("many")).is_err());
+        assert!(parse(&["--chunk-size"], None).is_err());
+    }
+
+    #[test]
+    fn test_take() {
+        let mut reader = WordReader::default();
+        reader.push(b"apple\nbanana\ncherry\n");
+
+        assert_eq!(reader.take(4), None);
+        assert_eq!(reader.take(2).unwrap(), vec!["apple", "banana"]);
+        assert_eq!(reader.words, vec!["cherry"]);
+    }
+
+    #[test]
+    fn test_reader_skip() {
+        let mut reader = WordReader {
+            skip: 2,
+            ..Default::default()
+        };
+        reader.push(b"apple\n\ndon't\nbanana\ncherry");
+        reader.finish();
+
+        assert_eq!(reader.words, vec!["cherry"]);
+    }
+
+    #[tokio::test]
+    async fn test_batches() {
+        let (batches, duplicates) = read_batches(3).await;
+
+        assert_eq!(
+            batches,
+            vec![vec!["apple", "banana", "cherry"], vec!["apple"]]
+        );
+        assert_eq!(duplicates, 0);
+    }
+
+    #[tokio::test]
+    async fn test_batches_cover_every_word() {
+        for chunk_size in 1..=3 {
+            let (batches, _) = read_batches(chunk_size).await;
+
+            assert!(batches.iter().all(|batch| batch.len() <= chunk_size));
+            assert_eq!(batches.concat(), vec!["apple", "banana", "cherry", "apple"]);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_progress_matches_inserted() {
+        let words: Vec<usize> = (0..12).collect();
+        let mut inserted = Vec::new();
+        let mut progress = 0;
+
+        let summary = insert_batches(
+            &mut words.chunks(5),
+            &args(5, false, 0),
+            |batch| {
+                inserted.extend(batch);
+                async { Ok::<(), String>(()) }
+            },
+            |n| progress += n,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(inserted, words);
+        assert_eq!(progress, 12);
+        assert_eq!(summary.succeeded, 3);
+    }
+
+    async fn fail_on(batch: Vec<usize>, first: usize) -> Result<(), String> {
+        if batch[0] == first {
+            Err(format!("batch starting at {} failed", first))
+        } else {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_progress_stops_on_failure() {
+        let words: Vec<usize> = (0..12).collect();
+        let mut progress = 0;
+
+        let result = insert_batches(
+            &mut words.chunks(5),
+            &args(5, false, 0),
+            |batch| fail_on(batch, 5),
+            |n| progress += n,
+        )
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(progress, 5);
+    }
+
+    #[tokio::test]
+    async fn test_keep_going_after_failure() {
+        let words: Vec<usize> = (0..12).collect();
+        let mut progress = 0;
+
+        let summary = insert_batches(
+            &mut words.chunks(5),
+            &args(5, true, 0),
+            |batch| fail_on(batch, 5),
+            |n| progress += n,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(summary.succeeded, 2);
+        assert_eq!(summary.failed.len(), 1);
+        assert_eq!(summary.failed[0], 5..10);
+        assert_eq!(progress, 7);
+    }
+
+    #[tokio::test]
+    async fn test_retry_failed_batch() {
+        let words: Vec<usize> = (0..12).collect();
+        let mut attempts = 0;
+
+        let summary = insert_batches(
+            &mut words.chunks(5),
+            &args(5, false, 1),
+            |batch| {
+                if batch[0] == 5 {
+                    attempts += 1;
+                }
+                let fail = batch[0] == 5 && attempts == 1;
+                async move {
+                    if fail {
+                        Err("transient".to_owned())
+                    } else {
+                        Ok(())
+                    }
+                }
+            },
+            |_| {},
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(summary.succeeded, 3);
+        assert_eq!(attempts, 2);
+    }
+}
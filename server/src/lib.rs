@@ -0,0 +1,79 @@
+#![cfg(feature = "server")]
+
+use std::sync::OnceLock;
+
+use axum::{http::StatusCode, routing::post, Json, Router};
+use dictionary::WordBank;
+use serde::{Deserialize, Serialize};
+use spellingbee::{LetterBank, Report, SpellingBeeSolver};
+
+type Rejection = (StatusCode, String);
+
+fn bad_request(err: impl ToString) -> Rejection {
+    (StatusCode::BAD_REQUEST, err.to_string())
+}
+
+// The solvers are CPU bound, so they run on the blocking pool rather than an async worker
+async fn blocking<T, F>(solve: F) -> Result<T, Rejection>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T, Rejection> + Send + 'static,
+{
+    tokio::task::spawn_blocking(solve)
+        .await
+        .map_err(|err| (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()))?
+}
+
+// The bundled word banks are parsed on first use and shared by every request
+fn wordle_bank() -> &'static WordBank {
+    static BANK: OnceLock<WordBank> = OnceLock::new();
+    BANK.get_or_init(|| {
+        WordBank::parse(include_str!("../../wordle/src/word_bank.txt")).with_length(5)
+    })
+}
+
+fn spellingbee_bank() -> &'static WordBank {
+    static BANK: OnceLock<WordBank> = OnceLock::new();
+    BANK.get_or_init(|| WordBank::parse(include_str!("../../spellingbee/src/word_bank.txt")))
+}
+
+#[derive(Debug, Deserialize)]
+struct SuggestRequest {
+    guesses: Vec<(String, String)>,
+}
+
+#[derive(Debug, Serialize)]
+struct SuggestResponse {
+    suggestion: String,
+}
+
+pub fn app() -> Router {
+    Router::new()
+        .route("/sudoku/solve", post(solve_sudoku))
+        .route("/wordle/suggest", post(suggest_word))
+        .route("/spellingbee/solve", post(solve_spellingbee))
+}
+
+async fn solve_sudoku(body: String) -> Result<String, Rejection> {
+    blocking(move || sudoku::solve_line(&body).map_err(bad_request)).await
+}
+
+async fn suggest_word(
+    Json(request): Json<SuggestRequest>,
+) -> Result<Json<SuggestResponse>, Rejection> {
+    let suggestion = blocking(move || {
+        wordle::suggest(wordle_bank().iter(), &request.guesses).map_err(bad_request)
+    })
+    .await?;
+
+    Ok(Json(SuggestResponse { suggestion }))
+}
+
+async fn solve_spellingbee(body: String) -> Result<Json<Report>, Rejection> {
+    let letters = LetterBank::parse_strict(&body).map_err(bad_request)?;
+    let report =
+        blocking(move || Ok(SpellingBeeSolver::new(letters, spellingbee_bank().iter()).report()))
+            .await?;
+
+    Ok(Json(report))
+}
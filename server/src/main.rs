@@ -0,0 +1,38 @@
+use std::io;
+
+use clap::Parser;
+use tokio::net::TcpListener;
+
+#[derive(Parser, Debug, PartialEq, Eq)]
+struct Args {
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1:3000")]
+    addr: String,
+}
+
+#[tokio::main]
+async fn main() -> io::Result<()> {
+    let args = Args::parse();
+    let listener = TcpListener::bind(&args.addr).await?;
+    println!("Listening on {}", listener.local_addr()?);
+
+    axum::serve(listener, server::app()).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod args {
+        use super::*;
+
+        #[test]
+        fn test_args() {
+            assert_eq!(Args::parse_from(["server"]).addr, "127.0.0.1:3000");
+            assert_eq!(
+                Args::parse_from(["server", "--addr", "0.0.0.0:8080"]).addr,
+                "0.0.0.0:8080"
+            );
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use http_body_util::BodyExt;
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+async fn post(uri: &str, body: impl Into<Body>) -> (StatusCode, String) {
+    let response = server::app()
+        .oneshot(
+            Request::post(uri)
+                .header("content-type", "application/json")
+                .body(body.into())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+fn line(grid: &str) -> String {
+    grid.lines().collect()
+}
+
+#[tokio::test]
+async fn test_sudoku_solve() {
+    let input = line(include_str!("../../sudoku/src/puzzles/easy/1/input.txt"));
+    let output = line(include_str!("../../sudoku/src/puzzles/easy/1/output.txt"));
+
+    assert_eq!(post("/sudoku/solve", input).await, (StatusCode::OK, output));
+    assert_eq!(
+        post("/sudoku/solve", "123").await.0,
+        StatusCode::BAD_REQUEST
+    );
+}
+
+#[tokio::test]
+async fn test_wordle_suggest() {
+    let (status, body) = post("/wordle/suggest", json!({ "guesses": [] }).to_string()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        serde_json::from_str::<Value>(&body).unwrap(),
+        json!({ "suggestion": "serai" })
+    );
+
+    let guesses = json!({ "guesses": [["serai", "bbbbb"], ["mount", "ybbyb"]] });
+    let (status, body) = post("/wordle/suggest", guesses.to_string()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        serde_json::from_str::<Value>(&body).unwrap(),
+        json!({ "suggestion": "nymph" })
+    );
+
+    let invalid = json!({ "guesses": [["serai", "bxgbb"]] });
+    assert_eq!(
+        post("/wordle/suggest", invalid.to_string()).await.0,
+        StatusCode::BAD_REQUEST
+    );
+}
+
+#[tokio::test]
+async fn test_spellingbee_solve() {
+    let (status, body) = post("/spellingbee/solve", "p:lrcphoy").await;
+    let report: Value = serde_json::from_str(&body).unwrap();
+    let solutions = report["solutions"].as_array().unwrap();
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(report["summary"]["words"], solutions.len());
+    assert!(solutions
+        .iter()
+        .all(|solution| solution["word"].as_str().unwrap().contains('p')));
+    assert_eq!(
+        post("/spellingbee/solve", "abc").await.0,
+        StatusCode::BAD_REQUEST
+    );
+}
@@ -0,0 +1,44 @@
+use std::collections::HashSet;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use spellingbee::LetterBank;
+
+fn word_bank() -> Vec<&'static str> {
+    include_str!("../src/word_bank.txt").lines().collect()
+}
+
+// The set comparison the bitmask check replaced, kept as a baseline
+fn matches_hashset(letters: &LetterBank, required: char, word: &str) -> bool {
+    let hash: HashSet<char> = word.chars().collect();
+
+    hash.contains(&required) && hash.difference(&letters.allowed).count() == 0
+}
+
+fn bench_matches(c: &mut Criterion) {
+    let letters: LetterBank = "lrcphoy".parse().unwrap();
+    let word_bank = word_bank();
+    c.bench_function("matches", |b| {
+        b.iter(|| {
+            word_bank
+                .iter()
+                .filter(|word| letters.matches(black_box(word)))
+                .count()
+        })
+    });
+}
+
+fn bench_matches_hashset(c: &mut Criterion) {
+    let letters: LetterBank = "lrcphoy".parse().unwrap();
+    let word_bank = word_bank();
+    c.bench_function("matches_hashset", |b| {
+        b.iter(|| {
+            word_bank
+                .iter()
+                .filter(|word| matches_hashset(&letters, 'l', black_box(word)))
+                .count()
+        })
+    });
+}
+
+criterion_group!(benches, bench_matches, bench_matches_hashset);
+criterion_main!(benches);
@@ -0,0 +1,1078 @@
+use std::{
+    borrow::Cow,
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    str::FromStr,
+};
+
+use errors::PuzzleError;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+fn normalize(word: &str) -> Cow<'_, str> {
+    if word.bytes().all(|b| b.is_ascii_lowercase()) {
+        return Cow::Borrowed(word);
+    }
+
+    Cow::Owned(
+        word.nfd()
+            .filter(|&c| !is_combining_mark(c))
+            .flat_map(char::to_lowercase)
+            .collect(),
+    )
+}
+
+fn normalize_letter(c: char) -> char {
+    normalize(c.encode_utf8(&mut [0; 4]))
+        .chars()
+        .next()
+        .unwrap_or(c)
+}
+
+fn letter_mask(word: &str) -> Option<u32> {
+    word.chars().try_fold(0, |mask, c| match c {
+        'a'..='z' => Some(mask | 1 << (c as u32 - 'a' as u32)),
+        _ => None,
+    })
+}
+
+pub struct LetterBank {
+    pub allowed: HashSet<char>,
+    required_mask: u32,
+    allowed_mask: u32,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum LetterBankError {
+    Empty,
+    InvalidCenter(String),
+    Duplicate(char),
+    InvalidLetter(char),
+    WrongCount(usize),
+}
+
+impl Display for LetterBankError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LetterBankError::Empty => write!(f, "Empty letter bank"),
+            LetterBankError::InvalidCenter(center) => {
+                write!(f, "Invalid center letter '{}'", center)
+            }
+            LetterBankError::Duplicate(c) => write!(f, "Duplicate letter '{}'", c),
+            LetterBankError::InvalidLetter(c) => write!(f, "Invalid letter '{}'", c),
+            LetterBankError::WrongCount(count) => {
+                write!(f, "Expected 7 distinct letters, found {}", count)
+            }
+        }
+    }
+}
+
+fn split_center(s: &str) -> Result<(char, &str), LetterBankError> {
+    match s.split_once(':') {
+        Some((center, letters)) => {
+            let mut chars = center.trim().chars();
+            match (chars.next(), chars.next()) {
+                (Some(center), None) => Ok((center, letters.trim())),
+                _ => Err(LetterBankError::InvalidCenter(center.to_owned())),
+            }
+        }
+        None => Ok((s.chars().next().ok_or(LetterBankError::Empty)?, s)),
+    }
+}
+
+impl From<LetterBankError> for PuzzleError {
+    fn from(err: LetterBankError) -> Self {
+        PuzzleError::parse(err.to_string())
+    }
+}
+
+impl FromStr for LetterBank {
+    type Err = PuzzleError;
+
+    // Seven distinct letters, the first or a "c:" prefix being the center
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(LetterBank::parse_strict(s)?)
+    }
+}
+
+impl LetterBank {
+    pub fn new(required: char, allowed: HashSet<char>) -> Result<Self, LetterBankError> {
+        let required = normalize_letter(required);
+        let mut allowed: HashSet<char> = allowed.into_iter().map(normalize_letter).collect();
+        allowed.insert(required);
+        let mask = |c: char| letter_mask(c.encode_utf8(&mut [0; 4]));
+        let required_mask = mask(required).ok_or(LetterBankError::InvalidLetter(required))?;
+        let allowed_mask = allowed.iter().try_fold(0, |allowed_mask, &c| {
+            Ok(allowed_mask | mask(c).ok_or(LetterBankError::InvalidLetter(c))?)
+        })?;
+
+        Ok(LetterBank {
+            allowed,
+            required_mask,
+            allowed_mask,
+        })
+    }
+
+    pub fn parse_strict(s: &str) -> Result<Self, LetterBankError> {
+        let s = normalize(s.trim());
+        let (center, letters) = split_center(&s)?;
+
+        if let Some(invalid) = letters
+            .chars()
+            .chain([center])
+            .find(|c| !c.is_ascii_lowercase())
+        {
+            return Err(LetterBankError::InvalidLetter(invalid));
+        }
+
+        let mut seen: HashSet<char> = HashSet::with_capacity(7);
+        if let Some(duplicate) = letters.chars().find(|&c| !seen.insert(c)) {
+            return Err(LetterBankError::Duplicate(duplicate));
+        }
+
+        let bank = LetterBank::new(center, letters.chars().collect())?;
+        if bank.allowed.len() != 7 {
+            return Err(LetterBankError::WrongCount(bank.allowed.len()));
+        }
+
+        Ok(bank)
+    }
+
+    pub fn matches(&self, word: &str) -> bool {
+        letter_mask(&normalize(word)).is_some_and(|mask| {
+            mask & !self.allowed_mask == 0 && mask & self.required_mask == self.required_mask
+        })
+    }
+
+    pub fn is_pangram(&self, word: &str) -> bool {
+        let hash: HashSet<char> = normalize(word).chars().collect();
+
+        hash == self.allowed
+    }
+
+    pub fn is_perfect_pangram(&self, word: &str) -> bool {
+        self.is_pangram(word) && normalize(word).chars().count() == self.allowed.len()
+    }
+
+    pub fn score(&self, word: &str) -> u32 {
+        let length = normalize(word).chars().count() as u32;
+        let bonus = if self.is_pangram(word) { 7 } else { 0 };
+
+        match length {
+            0..=3 => 0,
+            4 => 1 + bonus,
+            _ => length + bonus,
+        }
+    }
+}
+
+pub static RANKS: [(&str, u32); 10] = [
+    ("Beginner", 0),
+    ("Good Start", 2),
+    ("Moving Up", 5),
+    ("Good", 8),
+    ("Solid", 15),
+    ("Nice", 25),
+    ("Great", 40),
+    ("Amazing", 50),
+    ("Genius", 70),
+    ("Queen Bee", 100),
+];
+
+// The ranks in RANKS order, lowest first
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rank {
+    Beginner,
+    GoodStart,
+    MovingUp,
+    Good,
+    Solid,
+    Nice,
+    Great,
+    Amazing,
+    Genius,
+    QueenBee,
+}
+
+impl Rank {
+    pub const ALL: [Rank; 10] = [
+        Rank::Beginner,
+        Rank::GoodStart,
+        Rank::MovingUp,
+        Rank::Good,
+        Rank::Solid,
+        Rank::Nice,
+        Rank::Great,
+        Rank::Amazing,
+        Rank::Genius,
+        Rank::QueenBee,
+    ];
+}
+
+impl Display for Rank {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(RANKS[*self as usize].0)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ScoredSolution {
+    pub word: String,
+    pub score: u32,
+    pub is_pangram: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Summary {
+    pub words: usize,
+    pub pangrams: usize,
+    pub max_score: u32,
+    pub has_pangram: bool,
+}
+
+impl Summary {
+    pub fn new(solutions: &[ScoredSolution]) -> Self {
+        let pangrams = solutions
+            .iter()
+            .filter(|solution| solution.is_pangram)
+            .count();
+
+        Summary {
+            words: solutions.len(),
+            pangrams,
+            max_score: solutions.iter().map(|solution| solution.score).sum(),
+            has_pangram: pangrams > 0,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Report {
+    pub summary: Summary,
+    pub solutions: Vec<ScoredSolution>,
+}
+
+pub struct SpellingBeeSolver {
+    pub letters: LetterBank,
+    word_bank: Vec<String>,
+    min_len: usize,
+    max_len: Option<usize>,
+}
+
+#[derive(Default)]
+pub struct SpellingBeeSolverBuilder {
+    letters: Option<LetterBank>,
+    word_bank: Vec<String>,
+    min_len: Option<usize>,
+    max_len: Option<usize>,
+}
+
+impl SpellingBeeSolverBuilder {
+    pub fn letters(mut self, letters: LetterBank) -> Self {
+        self.letters = Some(letters);
+        self
+    }
+
+    pub fn word_bank<T>(mut self, word_bank: impl IntoIterator<Item = T>) -> Self
+    where
+        T: Into<String>,
+    {
+        self.word_bank = word_bank.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn min_len(mut self, min_len: usize) -> Self {
+        self.min_len = Some(min_len);
+        self
+    }
+
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.max_len = Some(max_len);
+        self
+    }
+
+    pub fn build(self) -> Result<SpellingBeeSolver, PuzzleError> {
+        let letters = self
+            .letters
+            .ok_or_else(|| PuzzleError::parse("Missing letter bank"))?;
+        let solver = SpellingBeeSolver::new(letters, self.word_bank);
+        let min_len = self.min_len.unwrap_or(solver.min_len);
+
+        Ok(solver.with_length_bounds(min_len, self.max_len))
+    }
+}
+
+impl SpellingBeeSolver {
+    pub fn builder() -> SpellingBeeSolverBuilder {
+        SpellingBeeSolverBuilder::default()
+    }
+
+    pub fn new<T>(letters: LetterBank, word_bank: impl IntoIterator<Item = T>) -> Self
+    where
+        T: Into<String>,
+    {
+        // Stored normalized so every count and length sees the letters the bank matched
+        let word_bank = word_bank
+            .into_iter()
+            .map(|word| {
+                let word = word.into();
+                match normalize(&word) {
+                    Cow::Borrowed(_) => word,
+                    Cow::Owned(normalized) => normalized,
+                }
+            })
+            .collect();
+
+        SpellingBeeSolver {
+            letters,
+            word_bank,
+            min_len: 4,
+            max_len: None,
+        }
+    }
+
+    pub fn with_length_bounds(mut self, min_len: usize, max_len: Option<usize>) -> Self {
+        self.min_len = min_len;
+        self.max_len = max_len;
+        self
+    }
+
+    fn within_length_bounds(&self, word: &str) -> bool {
+        let length = word.chars().count();
+
+        length >= self.min_len && self.max_len.is_none_or(|max_len| length <= max_len)
+    }
+
+    // Pangrams first, then by score, then alphabetically
+    pub fn solve(&self) -> Vec<(&str, u32)> {
+        let mut solution: Vec<(&str, u32)> = self
+            .word_bank
+            .iter()
+            .filter(|word| self.within_length_bounds(word))
+            .filter(|word| self.letters.matches(word))
+            .map(|word| (word.as_str(), self.letters.score(word)))
+            .collect();
+        solution.sort_by_key(|&(word, score)| {
+            (Reverse(self.letters.is_pangram(word)), Reverse(score), word)
+        });
+        solution
+    }
+
+    pub fn solve_limited(&self, n: usize) -> Vec<(&str, u32)> {
+        let mut solution = self.solve();
+        solution.truncate(n);
+        solution
+    }
+
+    pub fn scored_solutions(&self) -> Vec<ScoredSolution> {
+        self.solve()
+            .into_iter()
+            .map(|(word, score)| ScoredSolution {
+                word: word.to_owned(),
+                score,
+                is_pangram: self.letters.is_pangram(word),
+            })
+            .collect()
+    }
+
+    pub fn summary(&self) -> Summary {
+        Summary::new(&self.scored_solutions())
+    }
+
+    pub fn report(&self) -> Report {
+        let solutions = self.scored_solutions();
+
+        Report {
+            summary: Summary::new(&solutions),
+            solutions,
+        }
+    }
+
+    pub fn max_score(&self) -> u32 {
+        self.solve().into_iter().map(|(_, score)| score).sum()
+    }
+
+    pub fn thresholds(&self) -> Vec<(&'static str, u32)> {
+        let max_score = self.max_score();
+        RANKS
+            .iter()
+            .map(|&(rank, percent)| (rank, (max_score * percent + 50) / 100))
+            .collect()
+    }
+
+    // The highest rank whose threshold the score reaches
+    pub fn rank(&self, score: u32) -> Rank {
+        let reached = self
+            .thresholds()
+            .into_iter()
+            .take_while(|&(_, threshold)| threshold <= score)
+            .count();
+        Rank::ALL[reached.saturating_sub(1)]
+    }
+
+    pub fn hint_grid(&self) -> HashMap<(char, usize), usize> {
+        let mut grid: HashMap<(char, usize), usize> = HashMap::new();
+        self.solve().into_iter().for_each(|(word, _)| {
+            if let Some(first) = word.chars().next() {
+                *grid.entry((first, word.chars().count())).or_default() += 1;
+            }
+        });
+        grid
+    }
+
+    pub fn letter_distribution(&self) -> HashMap<char, usize> {
+        let mut distribution: HashMap<char, usize> =
+            self.letters.allowed.iter().map(|&c| (c, 0)).collect();
+        self.solve().into_iter().for_each(|(word, _)| {
+            let letters: HashSet<char> = word.chars().collect();
+            letters.into_iter().for_each(|c| {
+                *distribution.entry(c).or_default() += 1;
+            });
+        });
+        distribution
+    }
+
+    pub fn starting_letter_counts(&self) -> HashMap<char, usize> {
+        let mut counts: HashMap<char, usize> =
+            self.letters.allowed.iter().map(|&c| (c, 0)).collect();
+        self.solve().into_iter().for_each(|(word, _)| {
+            if let Some(first) = word.chars().next() {
+                *counts.entry(first).or_default() += 1;
+            }
+        });
+        counts
+    }
+
+    pub fn two_letter_prefixes(&self) -> HashMap<String, usize> {
+        let mut prefixes: HashMap<String, usize> = HashMap::new();
+        self.solve().into_iter().for_each(|(word, _)| {
+            *prefixes.entry(word.chars().take(2).collect()).or_default() += 1;
+        });
+        prefixes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod matches {
+        use super::*;
+
+        fn matches_hashset(letters: &LetterBank, required: char, word: &str) -> bool {
+            let hash: HashSet<char> = word.chars().collect();
+
+            hash.contains(&required) && hash.difference(&letters.allowed).count() == 0
+        }
+
+        #[test]
+        fn test_letter_mask() {
+            assert_eq!(letter_mask(""), Some(0));
+            assert_eq!(letter_mask("abba"), Some(0b11));
+            assert_eq!(letter_mask("zoo"), Some(1 << 25 | 1 << 14));
+            assert_eq!(letter_mask("it's"), None);
+        }
+
+        #[test]
+        fn test_matches_hashset() {
+            let letters: LetterBank = "lrcphoy".parse().unwrap();
+
+            include_str!("word_bank.txt").lines().for_each(|word| {
+                assert_eq!(
+                    letters.matches(word),
+                    matches_hashset(&letters, 'l', word),
+                    "{}",
+                    word
+                )
+            });
+        }
+    }
+
+    mod pangram {
+        use super::*;
+
+        #[test]
+        fn test_is_pangram() {
+            let letters: LetterBank = "lrcphoy".parse().unwrap();
+
+            assert!(letters.is_pangram("polychroy"));
+            assert!(letters.is_pangram("lrcphoy"));
+            assert!(!letters.is_pangram("holy"));
+            assert!(!letters.is_pangram("polychromy"));
+        }
+
+        #[test]
+        fn test_is_perfect_pangram() {
+            let letters: LetterBank = "lrcphoy".parse().unwrap();
+
+            assert!(letters.is_perfect_pangram("lrcphoy"));
+            assert!(!letters.is_perfect_pangram("polychroy"));
+            assert!(!letters.is_perfect_pangram("holy"));
+        }
+
+        #[test]
+        fn test_solve_pangrams() {
+            let letters: LetterBank = "lrcphoy".parse().unwrap();
+            let solver = SpellingBeeSolver::new(letters, vec!["holy", "polychroy", "hoop"]);
+
+            let pangrams: Vec<&str> = solver
+                .solve()
+                .into_iter()
+                .map(|(word, _)| word)
+                .filter(|word| solver.letters.is_pangram(word))
+                .collect();
+            assert_eq!(pangrams, vec!["polychroy"]);
+        }
+    }
+
+    mod score {
+        use super::*;
+
+        #[test]
+        fn test_four_letter_word() {
+            let letters: LetterBank = "lrcphoy".parse().unwrap();
+
+            assert_eq!(letters.score("holy"), 1);
+        }
+
+        #[test]
+        fn test_long_word() {
+            let letters: LetterBank = "lrcphoy".parse().unwrap();
+
+            assert_eq!(letters.score("hooply"), 6);
+        }
+
+        #[test]
+        fn test_pangram() {
+            let letters: LetterBank = "lrcphoy".parse().unwrap();
+
+            assert_eq!(letters.score("polychroy"), 9 + 7);
+            assert_eq!(letters.score("lrcphoy"), 7 + 7);
+        }
+
+        #[test]
+        fn test_solve_sorted_by_score() {
+            let letters: LetterBank = "lrcphoy".parse().unwrap();
+            let solver = SpellingBeeSolver::new(letters, vec!["holy", "polychroy", "hooply"]);
+
+            assert_eq!(
+                solver.solve(),
+                vec![("polychroy", 16), ("hooply", 6), ("holy", 1)]
+            );
+        }
+    }
+
+    mod solve {
+        use super::*;
+
+        #[test]
+        fn test_returns_all_matches() {
+            let letters: LetterBank = "lrcphoy".parse().unwrap();
+            let word_bank = vec![
+                "loop", "lory", "loch", "holy", "poly", "poll", "polo", "pool", "roll", "cool",
+                "ploy", "holo", "loco", "yolo", "clop", "prol", "holly", "color", "lorry", "loopy",
+                "polyp", "phyllo", "lyrpoch", "hoop", "crop",
+            ];
+            let solver = SpellingBeeSolver::new(letters, word_bank);
+
+            assert_eq!(solver.solve().len(), 23);
+        }
+
+        #[test]
+        fn test_pangrams_first() {
+            let letters: LetterBank = "lrcphoy".parse().unwrap();
+            let solver = SpellingBeeSolver::new(
+                letters,
+                vec!["poly", "hooplyhooplyhooply", "holy", "lrcphoy", "ploy"],
+            );
+
+            // The pangram scores 14 but still leads the 18 letter word
+            assert_eq!(
+                solver.solve(),
+                vec![
+                    ("lrcphoy", 14),
+                    ("hooplyhooplyhooply", 18),
+                    ("holy", 1),
+                    ("ploy", 1),
+                    ("poly", 1),
+                ]
+            );
+        }
+
+        #[test]
+        fn test_solve_limited() {
+            let letters: LetterBank = "lrcphoy".parse().unwrap();
+            let solver = SpellingBeeSolver::new(letters, vec!["holy", "polychroy", "hooply"]);
+
+            assert_eq!(
+                solver.solve_limited(2),
+                vec![("polychroy", 16), ("hooply", 6)]
+            );
+            assert_eq!(solver.solve_limited(5).len(), 3);
+        }
+    }
+
+    mod parse {
+        use super::*;
+
+        #[test]
+        fn test_empty() {
+            assert!("".parse::<LetterBank>().is_err());
+            assert!("  \n".parse::<LetterBank>().is_err());
+        }
+
+        #[test]
+        fn test_seven_letters() {
+            let letters = LetterBank::parse_strict(" LRCphoy\n").unwrap();
+
+            assert_eq!(Some(letters.required_mask), letter_mask("l"));
+            assert_eq!(letters.allowed, "lrcphoy".chars().collect());
+        }
+
+        #[test]
+        fn test_center_letter() {
+            let letters: LetterBank = "p:lrcphoy".parse().unwrap();
+
+            assert_eq!(Some(letters.required_mask), letter_mask("p"));
+            assert_eq!(letters.allowed, "lrcphoy".chars().collect());
+            assert!(letters.matches("poly"));
+            assert!(!letters.matches("holy"));
+        }
+
+        #[test]
+        fn test_center_letter_not_in_letters() {
+            let letters: LetterBank = "x:lrcpho".parse().unwrap();
+
+            assert!(letters.allowed.contains(&'x'));
+            assert!(matches!(
+                "xy:lrcphoy".parse::<LetterBank>(),
+                Err(PuzzleError::Parse { .. })
+            ));
+            assert!(matches!(
+                ":lrcphoy".parse::<LetterBank>(),
+                Err(PuzzleError::Parse { .. })
+            ));
+        }
+
+        #[test]
+        fn test_new() {
+            let letters = LetterBank::new('o', "lrcphy".chars().collect()).unwrap();
+
+            assert!(letters.matches("holy"));
+            assert!(!letters.matches("lych"));
+        }
+
+        #[test]
+        fn test_new_invalid_letter() {
+            assert_eq!(
+                LetterBank::new('4', "lrcphy".chars().collect()).err(),
+                Some(LetterBankError::InvalidLetter('4'))
+            );
+            assert_eq!(
+                LetterBank::new('o', "lrc-hy".chars().collect()).err(),
+                Some(LetterBankError::InvalidLetter('-'))
+            );
+        }
+
+        #[test]
+        fn test_three_letters() {
+            assert!("abc".parse::<LetterBank>().is_err());
+            assert_eq!(
+                LetterBank::parse_strict("abc").err(),
+                Some(LetterBankError::WrongCount(3))
+            );
+        }
+
+        #[test]
+        fn test_duplicate_letter() {
+            assert!(matches!(
+                "lrcphol".parse::<LetterBank>(),
+                Err(PuzzleError::Parse { .. })
+            ));
+            assert_eq!(
+                LetterBank::parse_strict("lrcphol").err(),
+                Some(LetterBankError::Duplicate('l'))
+            );
+            assert_eq!(
+                LetterBank::parse_strict("p:lrcphop").err(),
+                Some(LetterBankError::Duplicate('p'))
+            );
+        }
+
+        #[test]
+        fn test_six_letters() {
+            assert_eq!(
+                LetterBank::parse_strict("lrcpho").err(),
+                Some(LetterBankError::WrongCount(6))
+            );
+        }
+
+        #[test]
+        fn test_strict_errors() {
+            assert_eq!(
+                LetterBank::parse_strict("").err(),
+                Some(LetterBankError::Empty)
+            );
+            assert_eq!(
+                LetterBank::parse_strict("xy:lrcphoy").err(),
+                Some(LetterBankError::InvalidCenter("xy".to_owned()))
+            );
+            assert_eq!(
+                LetterBank::parse_strict("lrc4hoy").err(),
+                Some(LetterBankError::InvalidLetter('4'))
+            );
+            assert_eq!(
+                "lrc-hoy".parse::<LetterBank>().err().unwrap().to_string(),
+                "Could not parse puzzle: Invalid letter '-'"
+            );
+        }
+    }
+
+    mod hints {
+        use super::*;
+
+        fn solver() -> SpellingBeeSolver {
+            let letters: LetterBank = "lrcphoy".parse().unwrap();
+            SpellingBeeSolver::new(
+                letters,
+                vec![
+                    "holy", "hollo", "holly", "loop", "loopy", "poll", "polyp", "crop",
+                ],
+            )
+        }
+
+        #[test]
+        fn test_hint_grid() {
+            assert_eq!(
+                solver().hint_grid(),
+                HashMap::from([
+                    (('h', 4), 1),
+                    (('h', 5), 2),
+                    (('l', 4), 1),
+                    (('l', 5), 1),
+                    (('p', 4), 1),
+                    (('p', 5), 1),
+                ])
+            );
+        }
+
+        #[test]
+        fn test_letter_distribution() {
+            assert_eq!(
+                solver().letter_distribution(),
+                HashMap::from([
+                    ('l', 7),
+                    ('o', 7),
+                    ('h', 3),
+                    ('y', 4),
+                    ('p', 4),
+                    ('c', 0),
+                    ('r', 0),
+                ])
+            );
+        }
+
+        #[test]
+        fn test_starting_letter_counts() {
+            assert_eq!(
+                solver().starting_letter_counts(),
+                HashMap::from([
+                    ('h', 3),
+                    ('l', 2),
+                    ('p', 2),
+                    ('o', 0),
+                    ('y', 0),
+                    ('c', 0),
+                    ('r', 0),
+                ])
+            );
+        }
+
+        #[test]
+        fn test_two_letter_prefixes() {
+            assert_eq!(
+                solver().two_letter_prefixes(),
+                HashMap::from([
+                    ("ho".to_owned(), 3),
+                    ("lo".to_owned(), 2),
+                    ("po".to_owned(), 2)
+                ])
+            );
+        }
+    }
+
+    mod thresholds {
+        use super::*;
+
+        fn solver() -> SpellingBeeSolver {
+            let letters: LetterBank = "lrcphoy".parse().unwrap();
+            SpellingBeeSolver::new(letters, vec!["holy", "hooply", "polychroy", "lorry"])
+        }
+
+        #[test]
+        fn test_max_score() {
+            assert_eq!(solver().max_score(), 1 + 6 + 16 + 5);
+        }
+
+        #[test]
+        fn test_genius() {
+            let thresholds = solver().thresholds();
+
+            assert!(thresholds.contains(&("Beginner", 0)));
+            assert!(thresholds.contains(&("Genius", 20)));
+            assert!(thresholds.contains(&("Queen Bee", 28)));
+        }
+
+        #[test]
+        fn test_rank() {
+            let solver = solver();
+
+            assert_eq!(solver.rank(0), Rank::Beginner);
+            // Good Start and Moving Up both round to one point
+            assert_eq!(solver.rank(1), Rank::MovingUp);
+            assert_eq!(solver.rank(14), Rank::Amazing);
+            assert_eq!(solver.rank(19), Rank::Amazing);
+            assert_eq!(solver.rank(20), Rank::Genius);
+            assert_eq!(solver.rank(27), Rank::Genius);
+            assert_eq!(solver.rank(28), Rank::QueenBee);
+        }
+
+        #[test]
+        fn test_rank_display() {
+            let names: Vec<String> = Rank::ALL.iter().map(Rank::to_string).collect();
+
+            assert_eq!(names, RANKS.map(|(name, _)| name));
+            assert_eq!(Rank::QueenBee.to_string(), "Queen Bee");
+        }
+    }
+
+    mod word_bank {
+        use super::*;
+
+        #[test]
+        fn test_runtime_word_bank() {
+            let letters: LetterBank = "lrcphoy".parse().unwrap();
+            let word_bank: Vec<String> = "holy crop hooply"
+                .split_whitespace()
+                .map(|word| word.to_string())
+                .collect();
+            let solver = SpellingBeeSolver::new(letters, word_bank);
+
+            assert_eq!(solver.solve(), vec![("hooply", 6), ("holy", 1)]);
+        }
+    }
+
+    mod length_bounds {
+        use super::*;
+
+        fn solve(min_len: usize, max_len: Option<usize>) -> Vec<String> {
+            let letters: LetterBank = "lrcphoy".parse().unwrap();
+            let solver = SpellingBeeSolver::new(letters, vec!["lol", "holy", "holly", "hooply"])
+                .with_length_bounds(min_len, max_len);
+
+            let mut words: Vec<String> = solver
+                .solve()
+                .into_iter()
+                .map(|(word, _)| word.to_owned())
+                .collect();
+            words.sort();
+            words
+        }
+
+        #[test]
+        fn test_default_bounds() {
+            let letters: LetterBank = "lrcphoy".parse().unwrap();
+            let solver = SpellingBeeSolver::new(letters, vec!["lol", "holy", "hooply"]);
+
+            assert_eq!(solver.solve(), vec![("hooply", 6), ("holy", 1)]);
+        }
+
+        #[test]
+        fn test_custom_bounds() {
+            assert_eq!(solve(3, None), vec!["holly", "holy", "hooply", "lol"]);
+            assert_eq!(solve(5, None), vec!["holly", "hooply"]);
+            assert_eq!(solve(4, Some(5)), vec!["holly", "holy"]);
+            assert_eq!(solve(6, Some(6)), vec!["hooply"]);
+        }
+    }
+
+    mod report {
+        use super::*;
+
+        fn solver() -> SpellingBeeSolver {
+            let letters: LetterBank = "lrcphoy".parse().unwrap();
+            SpellingBeeSolver::new(letters, vec!["holy", "crop", "polychroy"])
+        }
+
+        #[test]
+        fn test_report() {
+            assert_eq!(
+                solver().report(),
+                Report {
+                    summary: Summary {
+                        words: 2,
+                        pangrams: 1,
+                        max_score: 17,
+                        has_pangram: true,
+                    },
+                    solutions: vec![
+                        ScoredSolution {
+                            word: "polychroy".to_owned(),
+                            score: 16,
+                            is_pangram: true,
+                        },
+                        ScoredSolution {
+                            word: "holy".to_owned(),
+                            score: 1,
+                            is_pangram: false,
+                        },
+                    ],
+                }
+            );
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn test_json_round_trip() {
+            let report = solver().report();
+            let json = serde_json::to_string(&report).unwrap();
+
+            assert_eq!(serde_json::from_str::<Report>(&json).unwrap(), report);
+        }
+    }
+
+    mod summary {
+        use super::*;
+
+        #[test]
+        fn test_single_pangram() {
+            let letters: LetterBank = "lrcphoy".parse().unwrap();
+            let solver =
+                SpellingBeeSolver::new(letters, vec!["holy", "hooply", "polychroy", "crop"]);
+
+            assert_eq!(
+                solver.summary(),
+                Summary {
+                    words: 3,
+                    pangrams: 1,
+                    max_score: 1 + 6 + 16,
+                    has_pangram: true,
+                }
+            );
+        }
+
+        #[test]
+        fn test_no_pangram() {
+            let letters: LetterBank = "lrcphoy".parse().unwrap();
+            let solver = SpellingBeeSolver::new(letters, vec!["holy", "hooply"]);
+
+            assert!(!solver.summary().has_pangram);
+            assert_eq!(solver.summary().pangrams, 0);
+        }
+    }
+
+    mod normalize {
+        use super::*;
+
+        #[test]
+        fn test_normalize() {
+            assert_eq!(normalize("holy"), "holy");
+            assert_eq!(normalize("HoLy"), "holy");
+            assert_eq!(normalize("Crêpe"), "crepe");
+            assert_eq!(normalize("PÔLÝ"), "poly");
+        }
+
+        #[test]
+        fn test_mixed_case() {
+            let letters: LetterBank = "lrcphoy".parse().unwrap();
+
+            assert!(letters.matches("Holy"));
+            assert!(letters.matches("HOOPLY"));
+            assert!(letters.is_pangram("PolyChroy"));
+        }
+
+        #[test]
+        fn test_accents() {
+            let letters: LetterBank = "lrcphoy".parse().unwrap();
+
+            assert!(letters.matches("hôly"));
+            assert!(letters.matches("pôlý"));
+            assert!(!letters.matches("hélo"));
+            assert_eq!(letters.score("hôoplý"), 6);
+        }
+
+        #[test]
+        fn test_solver_words() {
+            let letters: LetterBank = "lrcphoy".parse().unwrap();
+            let solver = SpellingBeeSolver::new(letters, vec!["Holy", "ho\u{301}ly", "POLY"])
+                .with_length_bounds(4, Some(4));
+
+            assert_eq!(solver.solve(), vec![("holy", 1), ("holy", 1), ("poly", 1)]);
+            assert_eq!(
+                solver.hint_grid(),
+                HashMap::from([(('h', 4), 2), (('p', 4), 1)])
+            );
+            assert_eq!(
+                solver.two_letter_prefixes(),
+                HashMap::from([("ho".to_owned(), 2), ("po".to_owned(), 1)])
+            );
+            assert_eq!(solver.starting_letter_counts()[&'h'], 2);
+        }
+
+        #[test]
+        fn test_uppercase_bank() {
+            let letters = LetterBank::new('L', "RCPHOY".chars().collect()).unwrap();
+
+            assert!(letters.matches("holy"));
+            assert!(letters.matches("HOLY"));
+        }
+    }
+
+    mod builder {
+        use super::*;
+
+        const WORD_BANK: [&str; 6] = ["lol", "holy", "holly", "hooply", "polychroy", "crop"];
+
+        #[test]
+        fn test_matches_constructor() {
+            let letters: LetterBank = "lrcphoy".parse().unwrap();
+            let positional = SpellingBeeSolver::new(letters, WORD_BANK);
+
+            let letters: LetterBank = "lrcphoy".parse().unwrap();
+            let built = SpellingBeeSolver::builder()
+                .letters(letters)
+                .word_bank(WORD_BANK)
+                .build()
+                .unwrap();
+
+            assert_eq!(built.solve(), positional.solve());
+        }
+
+        #[test]
+        fn test_length_bounds() {
+            let letters: LetterBank = "lrcphoy".parse().unwrap();
+            let solver = SpellingBeeSolver::builder()
+                .letters(letters)
+                .word_bank(WORD_BANK)
+                .min_len(3)
+                .max_len(5)
+                .build()
+                .unwrap();
+
+            assert_eq!(solver.solve(), vec![("holly", 5), ("holy", 1), ("lol", 0)]);
+        }
+
+        #[test]
+        fn test_missing_letters() {
+            assert!(matches!(
+                SpellingBeeSolver::builder().word_bank(WORD_BANK).build(),
+                Err(PuzzleError::Parse { .. })
+            ));
+        }
+    }
+}
@@ -1,59 +1,250 @@
-use std::{collections::HashSet, str::FromStr};
+use std::{
+    cmp::Reverse,
+    io::{self, IsTerminal, Read},
+    path::PathBuf,
+    process,
+};
 
-struct LetterBank {
-    required: HashSet<char>,
-    allowed: HashSet<char>,
-}
+use cli::{CommonArgs, Parser};
+use dictionary::WordBank;
+use spellingbee::{LetterBank, Report, SpellingBeeSolver};
 
-impl FromStr for LetterBank {
-    type Err = String;
+#[derive(Parser, Debug, Default, PartialEq, Eq)]
+struct Args {
+    #[command(flatten)]
+    common: CommonArgs,
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let required: HashSet<char> = HashSet::from([s.chars().next().unwrap()]);
-        let allowed: HashSet<char> = s.chars().collect();
+    /// Puzzle letters, e.g. "p:lrcphoy"; the --input file or stdin is used otherwise
+    #[arg(long)]
+    letters: Option<String>,
 
-        Ok(LetterBank { required, allowed })
-    }
+    /// Word list to solve against instead of the bundled word bank
+    #[arg(long)]
+    words: Option<PathBuf>,
+
+    /// Shortest word to accept, 4 by default
+    #[arg(long, value_parser = parse_len)]
+    min_len: Option<usize>,
+
+    /// Longest word to accept
+    #[arg(long, value_parser = parse_len)]
+    max_len: Option<usize>,
 }
 
-impl LetterBank {
-    fn matches(&self, word: &str) -> bool {
-        let hash: HashSet<char> = word.chars().collect();
+fn parse_len(value: &str) -> Result<usize, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid length {}", value))
+}
 
-        self.required.difference(&hash).count() == 0 && hash.difference(&self.allowed).count() == 0
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("{}", err);
+        process::exit(1);
     }
 }
 
-struct SpellingBeeSolver {
-    letters: LetterBank,
-    word_bank: Vec<&'static str>,
-}
+fn run() -> Result<(), String> {
+    let args = Args::parse();
+    args.common.init_tracing();
 
-impl SpellingBeeSolver {
-    fn new(letters: LetterBank, word_bank: Vec<&'static str>) -> Self {
-        SpellingBeeSolver { letters, word_bank }
+    let word_bank = match &args.words {
+        Some(path) => dictionary::load_from_file(path)
+            .map_err(|err| format!("Could not read word file {}: {}", path.display(), err))?,
+        None => WordBank::parse(include_str!("word_bank.txt")),
     }
+    .into_words();
+    let stdin = io::stdin();
+    let interactive = stdin.is_terminal();
+    let letters = match args.letters {
+        Some(letters) => Some(letters),
+        None => args
+            .common
+            .read_input()
+            .map_err(|err| format!("Could not read letters: {}", err))?,
+    };
+    let letters = LetterBank::parse_strict(&read_letters(letters, stdin, interactive)?)
+        .map_err(|err| err.to_string())?;
 
-    fn solve(&self) -> Vec<&'static str> {
-        self.word_bank
-            .iter()
-            .filter(|word| self.letters.matches(word))
-            .take(20)
-            .cloned()
-            .collect()
+    let mut builder = SpellingBeeSolver::builder()
+        .letters(letters)
+        .word_bank(word_bank)
+        .min_len(args.min_len.unwrap_or(4));
+    if let Some(max_len) = args.max_len {
+        builder = builder.max_len(max_len);
     }
-}
+    let solver = builder.build().map_err(|err| err.to_string())?;
 
-fn main() {
-    let mut word_bank: Vec<&'static str> = include_str!("word_bank.txt")
-        .lines()
-        .filter(|word| word.len() > 3)
-        .collect();
-    word_bank.sort_by(|a, b| a.len().cmp(&b.len()));
-    let letters: LetterBank = include_str!("letters.txt").parse().unwrap();
+    if args.common.json {
+        return print_json(&solver.report());
+    }
 
-    let solver = SpellingBeeSolver::new(letters, word_bank);
     let solution = solver.solve();
+    if args.common.quiet {
+        solution.iter().for_each(|(word, _)| println!("{}", word));
+        return Ok(());
+    }
+
+    println!("Top solutions: {:?}", solver.solve_limited(5));
 
     println!("Solutions: {:?}", solution);
+
+    let pangrams: Vec<&str> = solution
+        .iter()
+        .map(|&(word, _)| word)
+        .filter(|word| solver.letters.is_pangram(word))
+        .collect();
+    println!("Pangrams: {:?}", pangrams);
+
+    let perfect_pangrams: Vec<&str> = pangrams
+        .into_iter()
+        .filter(|word| solver.letters.is_perfect_pangram(word))
+        .collect();
+    println!("Perfect pangrams: {:?}", perfect_pangrams);
+
+    let summary = solver.summary();
+    println!(
+        "Summary: {} words, {} pangrams, {} points",
+        summary.words, summary.pangrams, summary.max_score
+    );
+    if !summary.has_pangram {
+        println!("Warning: no pangram found, the letter set may be invalid");
+    }
+
+    println!("Max score: {}", solver.max_score());
+    println!("Thresholds: {:?}", solver.thresholds());
+
+    let mut hint_grid: Vec<((char, usize), usize)> = solver.hint_grid().into_iter().collect();
+    hint_grid.sort();
+    println!("Hint grid: {:?}", hint_grid);
+
+    let mut prefixes: Vec<(String, usize)> = solver.two_letter_prefixes().into_iter().collect();
+    prefixes.sort();
+    println!("Two letter list: {:?}", prefixes);
+
+    let mut distribution: Vec<(char, usize)> = solver.letter_distribution().into_iter().collect();
+    distribution.sort_by_key(|&(c, count)| (Reverse(count), c));
+    println!("Letter distribution: {:?}", distribution);
+
+    let mut starting: Vec<(char, usize)> = solver.starting_letter_counts().into_iter().collect();
+    starting.sort_by_key(|&(c, count)| (Reverse(count), c));
+    println!("Starting letters: {:?}", starting);
+
+    Ok(())
+}
+
+fn read_letters(
+    letters: Option<String>,
+    mut input: impl Read,
+    interactive: bool,
+) -> Result<String, String> {
+    match letters {
+        Some(letters) => Ok(letters),
+        None if !interactive => {
+            let mut letters = String::new();
+            input
+                .read_to_string(&mut letters)
+                .map_err(|err| format!("Could not read letters from stdin: {}", err))?;
+            Ok(letters)
+        }
+        None => Ok(include_str!("letters.txt").to_owned()),
+    }
+}
+
+#[cfg(feature = "serde")]
+fn print_json(report: &Report) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(report).map_err(|err| err.to_string())?;
+    println!("{}", json);
+
+    Ok(())
+}
+
+#[cfg(not(feature = "serde"))]
+fn print_json(_report: &Report) -> Result<(), String> {
+    Err("JSON output requires the serde feature".to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod args {
+        use super::*;
+
+        fn parse(args: &[&str]) -> Result<Args, clap::Error> {
+            Args::try_parse_from(["spellingbee"].iter().chain(args))
+        }
+
+        #[test]
+        fn test_defaults() {
+            assert_eq!(parse(&[]).unwrap(), Args::default());
+        }
+
+        #[test]
+        fn test_overrides() {
+            assert_eq!(
+                parse(&["--letters", "abcdefg", "--words", "path.txt"]).unwrap(),
+                Args {
+                    letters: Some("abcdefg".to_owned()),
+                    words: Some("path.txt".into()),
+                    ..Args::default()
+                }
+            );
+        }
+
+        #[test]
+        fn test_length_bounds() {
+            let args = parse(&["--min-len", "5", "--max-len", "8"]).unwrap();
+
+            assert_eq!(args.min_len, Some(5));
+            assert_eq!(args.max_len, Some(8));
+            assert!(parse(&["--min-len", "five"]).is_err());
+        }
+
+        #[test]
+        fn test_common() {
+            let args = parse(&["--json", "--quiet", "--input", "letters.txt"]).unwrap();
+
+            assert!(args.common.json);
+            assert!(args.common.quiet);
+            assert_eq!(args.common.input, Some("letters.txt".into()));
+        }
+
+        #[test]
+        fn test_errors() {
+            assert!(parse(&["--letters"]).is_err());
+            assert!(parse(&["--bogus"]).is_err());
+        }
+    }
+
+    mod stdin {
+        use super::*;
+        use std::io::Cursor;
+
+        #[test]
+        fn test_read_from_stdin() {
+            let letters = read_letters(None, Cursor::new("p:lrcphoy\n"), false).unwrap();
+            let letters = LetterBank::parse_strict(&letters).unwrap();
+            let solver = SpellingBeeSolver::new(letters, vec!["holy", "hooply", "poly", "crop"]);
+
+            assert_eq!(
+                solver.solve(),
+                vec![("hooply", 6), ("crop", 1), ("poly", 1)]
+            );
+        }
+
+        #[test]
+        fn test_argument_takes_precedence() {
+            let letters = read_letters(Some("abcdefg".to_owned()), Cursor::new("lrcphoy"), false);
+
+            assert_eq!(letters, Ok("abcdefg".to_owned()));
+        }
+
+        #[test]
+        fn test_interactive_uses_bundled_letters() {
+            let letters = read_letters(None, Cursor::new("abcdefg"), true);
+
+            assert_eq!(letters, Ok(include_str!("letters.txt").to_owned()));
+        }
+    }
 }
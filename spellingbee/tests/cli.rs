@@ -0,0 +1,59 @@
+use std::{
+    fs,
+    path::PathBuf,
+    process::{Command, Output},
+};
+
+fn spellingbee(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_spellingbee"))
+        .args(args)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .unwrap()
+}
+
+fn word_file(name: &str, words: &str) -> PathBuf {
+    let path = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name);
+    fs::write(&path, words).unwrap();
+    path
+}
+
+#[test]
+fn test_letters_and_words_arguments() {
+    let words = word_file("spellingbee_words.txt", "holy\ncrop\nhooply\nlrcphoy\n");
+    let output = spellingbee(&[
+        "--letters",
+        "lrcphoy",
+        "--words",
+        words.to_str().unwrap(),
+        "--quiet",
+    ]);
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "lrcphoy\nhooply\nholy\n"
+    );
+}
+
+#[test]
+fn test_invalid_letters_exit_non_zero() {
+    let output = spellingbee(&["--letters", "lrcph", "--quiet"]);
+
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "Expected 7 distinct letters, found 5\n"
+    );
+}
+
+#[test]
+fn test_missing_word_file_exit_non_zero() {
+    let output = spellingbee(&["--letters", "lrcphoy", "--words", "missing.txt"]);
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .starts_with("Could not read word file missing.txt"));
+}
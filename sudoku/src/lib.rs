@@ -0,0 +1,2944 @@
+use std::{collections::HashSet, fmt::Display, str::FromStr};
+
+use errors::PuzzleError;
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+use tracing::{debug, info};
+
+mod search;
+
+use search::{backtrack, count_solutions, fill};
+
+// One symbol per digit, 1-9 then A-P, so a 16x16 board still fits one character per cell
+const SYMBOLS: &[u8] = b"123456789ABCDEFGHIJKLMNOP";
+
+// A digit from 1 up to the board size, or 0 for an empty cell
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CellValue(u8);
+
+impl CellValue {
+    pub const EMPTY: CellValue = CellValue(0);
+    pub const ONE: CellValue = CellValue(1);
+    pub const TWO: CellValue = CellValue(2);
+    pub const THREE: CellValue = CellValue(3);
+    pub const FOUR: CellValue = CellValue(4);
+    pub const FIVE: CellValue = CellValue(5);
+    pub const SIX: CellValue = CellValue(6);
+    pub const SEVEN: CellValue = CellValue(7);
+    pub const EIGHT: CellValue = CellValue(8);
+    pub const NINE: CellValue = CellValue(9);
+
+    pub fn digit(self) -> usize {
+        self.0 as usize
+    }
+}
+
+// Every digit of a board whose boxes are N cells wide
+fn digits<const N: usize>() -> impl Iterator<Item = CellValue> {
+    (1..=(N * N) as u8).map(CellValue)
+}
+
+impl Display for CellValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            0 => f.write_str("-"),
+            digit => write!(f, "{}", SYMBOLS[digit as usize - 1] as char),
+        }
+    }
+}
+
+// N is the box width, so N = 2 indexes a 4x4 board and N = 4 a 16x16 one
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CellIndex<const N: usize = 3> {
+    x: usize,
+    y: usize,
+}
+
+impl<const N: usize> CellIndex<N> {
+    pub fn new(x: usize, y: usize) -> Result<Self, PuzzleError> {
+        if x >= N * N || y >= N * N {
+            return Err(PuzzleError::OutOfBounds { x, y });
+        }
+        Ok(CellIndex { x, y })
+    }
+
+    pub fn x(&self) -> usize {
+        self.x
+    }
+
+    pub fn y(&self) -> usize {
+        self.y
+    }
+
+    fn subgrid(&self) -> usize {
+        (self.y / N) * N + self.x / N
+    }
+}
+
+#[derive(Clone, Debug)]
+struct RowIndex<const N: usize> {
+    idx: usize,
+}
+
+impl<const N: usize> RowIndex<N> {
+    fn new(idx: usize) -> Self {
+        RowIndex { idx }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ColumnIndex<const N: usize> {
+    idx: usize,
+}
+
+impl<const N: usize> ColumnIndex<N> {
+    fn new(idx: usize) -> Self {
+        ColumnIndex { idx }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct SubgridIndex<const N: usize> {
+    idx: usize,
+}
+
+impl<const N: usize> SubgridIndex<N> {
+    fn new(idx: usize) -> Self {
+        SubgridIndex { idx }
+    }
+}
+
+// 0 runs from the top left to the bottom right, 1 from the top right to the bottom left
+#[derive(Clone, Debug)]
+struct DiagonalIndex<const N: usize> {
+    idx: usize,
+}
+
+impl<const N: usize> DiagonalIndex<N> {
+    fn new(idx: usize) -> Self {
+        DiagonalIndex { idx }
+    }
+}
+
+trait Index<const N: usize> {
+    fn cells(&self) -> Vec<CellIndex<N>>;
+    fn name(&self) -> String;
+}
+
+impl<const N: usize> Index<N> for DiagonalIndex<N> {
+    fn cells(&self) -> Vec<CellIndex<N>> {
+        (0..N * N)
+            .map(|y| CellIndex {
+                x: if self.idx == 0 { y } else { N * N - 1 - y },
+                y,
+            })
+            .collect()
+    }
+
+    fn name(&self) -> String {
+        if self.idx == 0 {
+            "main diagonal".to_owned()
+        } else {
+            "anti-diagonal".to_owned()
+        }
+    }
+}
+
+impl<const N: usize> Index<N> for RowIndex<N> {
+    fn cells(&self) -> Vec<CellIndex<N>> {
+        (0..N * N).map(|x| CellIndex { x, y: self.idx }).collect()
+    }
+
+    fn name(&self) -> String {
+        format!("row {}", self.idx + 1)
+    }
+}
+
+impl<const N: usize> Index<N> for ColumnIndex<N> {
+    fn cells(&self) -> Vec<CellIndex<N>> {
+        (0..N * N).map(|y| CellIndex { x: self.idx, y }).collect()
+    }
+
+    fn name(&self) -> String {
+        format!("column {}", self.idx + 1)
+    }
+}
+
+impl<const N: usize> Index<N> for SubgridIndex<N> {
+    fn name(&self) -> String {
+        format!("subgrid {}", self.idx + 1)
+    }
+
+    fn cells(&self) -> Vec<CellIndex<N>> {
+        let root_x = (self.idx % N) * N;
+        let root_y = (self.idx / N) * N;
+        (0..N * N)
+            .map(|idx| CellIndex {
+                x: root_x + (idx % N),
+                y: root_y + (idx / N),
+            })
+            .collect()
+    }
+}
+
+struct Cell<'a, const N: usize> {
+    puzzle: &'a Puzzle<N>,
+    idx: CellIndex<N>,
+}
+
+impl<'a, const N: usize> Cell<'a, N> {
+    fn new(puzzle: &'a Puzzle<N>, idx: CellIndex<N>) -> Self {
+        Cell { puzzle, idx }
+    }
+
+    fn value(&self) -> CellValue {
+        self.puzzle.0[self.idx.y][self.idx.x]
+    }
+
+    fn row(&self) -> Section<'_, N, RowIndex<N>> {
+        self.puzzle.get_row(RowIndex::new(self.idx.y))
+    }
+
+    fn col(&self) -> Section<'_, N, ColumnIndex<N>> {
+        self.puzzle.get_col(ColumnIndex::new(self.idx.x))
+    }
+
+    fn subgrid(&self) -> Section<'_, N, SubgridIndex<N>> {
+        self.puzzle
+            .get_subgrid(SubgridIndex::new(self.idx.subgrid()))
+    }
+
+    fn get_eliminated_values(&self) -> Vec<CellValue> {
+        vec![
+            self.row().nonempty_cells(),
+            self.col().nonempty_cells(),
+            self.subgrid().nonempty_cells(),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|cell| cell.value())
+        .collect::<HashSet<CellValue>>()
+        .into_iter()
+        .collect()
+    }
+
+    fn get_possible_values(&self) -> Vec<CellValue> {
+        let complete: HashSet<CellValue> = digits::<N>().collect();
+        let eliminated: HashSet<CellValue> = self.get_eliminated_values().into_iter().collect();
+        complete.difference(&eliminated).copied().collect()
+    }
+}
+
+#[derive(Debug)]
+struct Section<'a, const N: usize, T>
+where
+    T: Index<N>,
+{
+    puzzle: &'a Puzzle<N>,
+    idx: T,
+}
+
+impl<'a, const N: usize, T> Section<'a, N, T>
+where
+    T: Index<N> + std::fmt::Debug,
+{
+    fn new(puzzle: &'a Puzzle<N>, idx: T) -> Self {
+        Section { puzzle, idx }
+    }
+
+    fn cells(&self) -> Vec<Cell<'_, N>> {
+        self.idx
+            .cells()
+            .into_iter()
+            .map(|idx| self.puzzle.get_cell(idx))
+            .collect()
+    }
+
+    fn nonempty_cells(&self) -> Vec<Cell<'_, N>> {
+        self.cells()
+            .into_iter()
+            .filter(|cell| cell.value() != CellValue::EMPTY)
+            .collect()
+    }
+
+    fn empty_cells(&self) -> Vec<Cell<'_, N>> {
+        self.cells()
+            .into_iter()
+            .filter(|cell| cell.value() == CellValue::EMPTY)
+            .collect()
+    }
+
+    fn is_valid(&self) -> bool {
+        let set: HashSet<CellValue> = self
+            .cells()
+            .into_iter()
+            .map(|cell| cell.value())
+            .filter(|value| value != &CellValue::EMPTY)
+            .collect();
+
+        let values: Vec<CellValue> = self
+            .cells()
+            .into_iter()
+            .map(|cell| cell.value())
+            .filter(|value| value != &CellValue::EMPTY)
+            .collect();
+
+        set.len() == values.len()
+    }
+
+    fn duplicate(&self) -> Option<CellValue> {
+        let mut seen: HashSet<CellValue> = HashSet::with_capacity(N * N);
+        self.nonempty_cells()
+            .into_iter()
+            .map(|cell| cell.value())
+            .find(|&value| !seen.insert(value))
+    }
+
+    fn check(&self) -> Result<(), ParseError> {
+        match self.duplicate() {
+            Some(value) => Err(ParseError::Duplicate {
+                value,
+                section: self.idx.name(),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    fn is_complete(&self) -> bool {
+        let set: HashSet<CellValue> = self.cells().into_iter().map(|cell| cell.value()).collect();
+
+        !set.contains(&CellValue::EMPTY) && set.len() == N * N
+    }
+}
+
+// Rows of cells on a board of N x N boxes, each N cells wide, so the default N = 3 is the
+// usual 9x9 board
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Puzzle<const N: usize = 3>(pub Vec<Vec<CellValue>>);
+
+// Extra constraints on top of the rows, columns and subgrids
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum Variant {
+    #[default]
+    Standard,
+    // Both main diagonals also hold every digit once each
+    Diagonal,
+}
+
+impl<const N: usize> Puzzle<N> {
+    pub const SIZE: usize = N * N;
+
+    // Candidate masks are u32s with bit 0 unused
+    const FITS: () = assert!(N > 1 && N * N < 32, "Box size too large");
+
+    pub fn empty() -> Self {
+        let () = Self::FITS;
+        Puzzle(vec![vec![CellValue::EMPTY; Self::SIZE]; Self::SIZE])
+    }
+
+    fn get_cell(&self, idx: CellIndex<N>) -> Cell<'_, N> {
+        Cell::new(self, idx)
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Result<CellValue, PuzzleError> {
+        Ok(self.get_cell(CellIndex::new(x, y)?).value())
+    }
+
+    fn get_cells(&self) -> Vec<Cell<'_, N>> {
+        (0..Self::SIZE)
+            .flat_map(|y| (0..Self::SIZE).map(move |x| CellIndex { x, y }))
+            .map(|idx| Cell::new(self, idx))
+            .collect()
+    }
+
+    fn get_empty_cells(&self) -> Vec<Cell<'_, N>> {
+        self.get_cells()
+            .into_iter()
+            .filter(|cell| cell.value() == CellValue::EMPTY)
+            .collect()
+    }
+
+    fn set_cell(&mut self, idx: CellIndex<N>, value: CellValue) {
+        self.0[idx.y][idx.x] = value;
+    }
+
+    fn get_row(&self, idx: RowIndex<N>) -> Section<'_, N, RowIndex<N>> {
+        Section::new(self, idx)
+    }
+
+    fn get_rows(&self) -> Vec<Section<'_, N, RowIndex<N>>> {
+        (0..Self::SIZE)
+            .map(RowIndex::new)
+            .map(|idx| self.get_row(idx))
+            .collect()
+    }
+
+    fn get_col(&self, idx: ColumnIndex<N>) -> Section<'_, N, ColumnIndex<N>> {
+        Section::new(self, idx)
+    }
+
+    fn get_cols(&self) -> Vec<Section<'_, N, ColumnIndex<N>>> {
+        (0..Self::SIZE)
+            .map(ColumnIndex::new)
+            .map(|idx| self.get_col(idx))
+            .collect()
+    }
+
+    fn get_subgrid(&self, idx: SubgridIndex<N>) -> Section<'_, N, SubgridIndex<N>> {
+        Section::new(self, idx)
+    }
+
+    fn get_subgrids(&self) -> Vec<Section<'_, N, SubgridIndex<N>>> {
+        (0..Self::SIZE)
+            .map(SubgridIndex::new)
+            .map(|idx| self.get_subgrid(idx))
+            .collect()
+    }
+
+    fn get_diagonals(&self) -> Vec<Section<'_, N, DiagonalIndex<N>>> {
+        (0..2)
+            .map(DiagonalIndex::new)
+            .map(|idx| Section::new(self, idx))
+            .collect()
+    }
+
+    // Single-line boards use '.', '0' or ' ' for blanks
+    pub fn from_line(line: &str) -> Result<Self, ParseError> {
+        let cells: Vec<char> = line.chars().collect();
+        if cells.len() != Self::SIZE * Self::SIZE {
+            return Err(ParseError::WrongLength {
+                expected: Self::SIZE * Self::SIZE,
+                found: cells.len(),
+            });
+        }
+
+        let mut puzzle = Puzzle::empty();
+        for (idx, ch) in cells.into_iter().enumerate() {
+            let (x, y) = (idx % Self::SIZE, idx / Self::SIZE);
+            puzzle.0[y][x] = parse_cell::<N>(y, x, ch)?;
+        }
+        Ok(puzzle)
+    }
+
+    // The inverse of from_line, with '0' for blanks
+    pub fn to_line(&self) -> String {
+        self.0
+            .iter()
+            .flatten()
+            .map(|&value| match value {
+                CellValue::EMPTY => '0'.to_string(),
+                value => value.to_string(),
+            })
+            .collect()
+    }
+
+    pub fn display_with(&self, placeholder: char) -> PuzzleDisplay<'_, N> {
+        PuzzleDisplay {
+            puzzle: self,
+            placeholder,
+        }
+    }
+
+    pub fn solution_count(&self) -> usize {
+        self.solution_count_for(Variant::Standard)
+    }
+
+    pub fn solution_count_for(&self, variant: Variant) -> usize {
+        if !self.is_valid_for(variant) {
+            return 0;
+        }
+
+        count_solutions(self, variant)
+    }
+
+    pub fn candidates(&self) -> Candidates<N> {
+        Candidates::new(self)
+    }
+
+    // The easiest deduction available, without changing the puzzle
+    pub fn hint(&self) -> Option<(CellIndex<N>, CellValue, &'static str)> {
+        if !self.is_valid() {
+            return None;
+        }
+
+        let mut candidates = Candidates::new(self);
+        let step = last_possible(self)
+            .into_iter()
+            .chain(last_remaining(self))
+            .next()
+            .or_else(|| {
+                naked_pairs(&mut candidates);
+                candidates.singles("naked pairs").into_iter().next()
+            })
+            .or_else(|| {
+                naked_triples(&mut candidates);
+                candidates.singles("naked triples").into_iter().next()
+            })
+            .or_else(|| {
+                pointing_pairs(&mut candidates);
+                box_line_reduction(&mut candidates);
+                candidates.singles("box/line reduction").into_iter().next()
+            })
+            .or_else(|| {
+                hidden_pairs(&mut candidates);
+                hidden_triples(&mut candidates);
+                candidates.singles("hidden subsets").into_iter().next()
+            })
+            .or_else(|| {
+                x_wing(&mut candidates);
+                candidates.singles("x-wing").into_iter().next()
+            })
+            .or_else(|| {
+                swordfish(&mut candidates);
+                candidates.singles("swordfish").into_iter().next()
+            })?;
+        Some((step.idx, step.value, step.technique))
+    }
+
+    pub fn difficulty(&self) -> Difficulty {
+        let mut solver = Solver::from(self.clone());
+        // Unsolvable puzzles are still rated by how far the solver got
+        let _ = solver.solve();
+        solver.difficulty()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.get_rows().into_iter().all(|row| row.is_valid())
+            && self.get_cols().into_iter().all(|col| col.is_valid())
+            && self
+                .get_subgrids()
+                .into_iter()
+                .all(|subgrid| subgrid.is_valid())
+    }
+
+    pub fn is_valid_for(&self, variant: Variant) -> bool {
+        self.is_valid()
+            && (variant == Variant::Standard
+                || self
+                    .get_diagonals()
+                    .into_iter()
+                    .all(|diagonal| diagonal.is_valid()))
+    }
+
+    // Pairs of filled cells sharing a row, column or subgrid with the same value, each
+    // pair once with the first cell in reading order
+    pub fn conflicts(&self) -> Vec<(CellIndex<N>, CellIndex<N>)> {
+        let filled: Vec<Cell<N>> = self
+            .get_cells()
+            .into_iter()
+            .filter(|cell| cell.value() != CellValue::EMPTY)
+            .collect();
+
+        filled
+            .iter()
+            .enumerate()
+            .flat_map(|(i, a)| filled[i + 1..].iter().map(move |b| (a, b)))
+            .filter(|(a, b)| a.value() == b.value())
+            .filter(|(a, b)| {
+                a.idx.x == b.idx.x || a.idx.y == b.idx.y || a.idx.subgrid() == b.idx.subgrid()
+            })
+            .map(|(a, b)| (a.idx.clone(), b.idx.clone()))
+            .collect()
+    }
+
+    pub fn validate(&self) -> Result<(), ParseError> {
+        self.get_rows().iter().try_for_each(Section::check)?;
+        self.get_cols().iter().try_for_each(Section::check)?;
+        self.get_subgrids().iter().try_for_each(Section::check)
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.get_rows().into_iter().all(|row| row.is_complete())
+            && self.get_cols().into_iter().all(|col| col.is_complete())
+            && self
+                .get_subgrids()
+                .into_iter()
+                .all(|subgrid| subgrid.is_complete())
+    }
+
+    pub fn is_complete_for(&self, variant: Variant) -> bool {
+        self.is_complete()
+            && (variant == Variant::Standard
+                || self
+                    .get_diagonals()
+                    .into_iter()
+                    .all(|diagonal| diagonal.is_complete()))
+    }
+}
+
+pub struct PuzzleDisplay<'a, const N: usize = 3> {
+    puzzle: &'a Puzzle<N>,
+    placeholder: char,
+}
+
+// Bordered by default; the alternate form `{:#}` keeps the plain layout with spaces
+// between boxes and blank lines between bands
+impl<const N: usize> Display for PuzzleDisplay<'_, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (column_separator, band_separator) = if f.alternate() {
+            (" ", String::new())
+        } else {
+            ("|", vec!["-".repeat(N); N].join("+"))
+        };
+
+        for (y, row) in self.puzzle.0.iter().enumerate() {
+            if y % N == 0 && y != 0 {
+                writeln!(f, "{}", band_separator)?;
+            }
+            for (x, value) in row.iter().enumerate() {
+                if x % N == 0 && x != 0 {
+                    f.write_str(column_separator)?;
+                }
+                match *value {
+                    CellValue::EMPTY => write!(f, "{}", self.placeholder)?,
+                    value => write!(f, "{}", value)?,
+                }
+            }
+            if y != N * N - 1 {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize> Display for Puzzle<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let placeholder = if f.alternate() { '-' } else { '.' };
+        self.display_with(placeholder).fmt(f)
+    }
+}
+
+// Symbols are case insensitive; anything else is empty
+impl From<char> for CellValue {
+    fn from(c: char) -> Self {
+        SYMBOLS
+            .iter()
+            .position(|&symbol| symbol as char == c.to_ascii_uppercase())
+            .map_or(CellValue::EMPTY, |idx| CellValue(idx as u8 + 1))
+    }
+}
+
+// Rows and columns are counted from 1, as in the messages
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    WrongRowCount {
+        expected: usize,
+        found: usize,
+    },
+    WrongColCount {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    WrongLength {
+        expected: usize,
+        found: usize,
+    },
+    InvalidChar {
+        row: usize,
+        col: usize,
+        ch: char,
+    },
+    Duplicate {
+        value: CellValue,
+        section: String,
+    },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::WrongRowCount { expected, found } => {
+                write!(f, "Expected {} rows, found {}", expected, found)
+            }
+            ParseError::WrongColCount {
+                row,
+                expected,
+                found,
+            } => {
+                write!(
+                    f,
+                    "Expected {} cells in row {}, found {}",
+                    expected, row, found
+                )
+            }
+            ParseError::WrongLength { expected, found } => {
+                write!(f, "Expected {} cells, found {}", expected, found)
+            }
+            ParseError::InvalidChar { row, col, ch } => {
+                write!(f, "Unexpected {:?} in row {}, column {}", ch, row, col)
+            }
+            ParseError::Duplicate { value, section } => {
+                write!(f, "duplicate {} in {}", value, section)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<ParseError> for PuzzleError {
+    fn from(err: ParseError) -> Self {
+        PuzzleError::parse(err.to_string())
+    }
+}
+
+// Symbols up to the board size fill a cell; '.', '0', '-' and ' ' leave it blank
+fn parse_cell<const N: usize>(row: usize, col: usize, ch: char) -> Result<CellValue, ParseError> {
+    match (ch, CellValue::from(ch)) {
+        ('.' | '0' | '-' | ' ', _) => Ok(CellValue::EMPTY),
+        (_, value) if value != CellValue::EMPTY && value.digit() <= N * N => Ok(value),
+        _ => Err(ParseError::InvalidChar {
+            row: row + 1,
+            col: col + 1,
+            ch,
+        }),
+    }
+}
+
+impl<const N: usize> FromStr for Puzzle<N> {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let size = Self::SIZE;
+        let line = s.trim_matches(['\r', '\n']);
+        if !line.contains('\n') {
+            let puzzle = Puzzle::from_line(line)?;
+            puzzle.validate()?;
+            return Ok(puzzle);
+        }
+
+        // Blank lines between bands are skipped
+        let rows: Vec<&str> = s
+            .lines()
+            .map(|row| row.trim_end_matches('\r'))
+            .filter(|row| !row.is_empty())
+            .collect();
+        if rows.len() != size {
+            return Err(ParseError::WrongRowCount {
+                expected: size,
+                found: rows.len(),
+            });
+        }
+
+        let mut puzzle = Puzzle::empty();
+        for (y, row) in rows.into_iter().enumerate() {
+            let cells: Vec<char> = row.chars().collect();
+            if cells.len() != size {
+                return Err(ParseError::WrongColCount {
+                    row: y + 1,
+                    expected: size,
+                    found: cells.len(),
+                });
+            }
+            for (x, ch) in cells.into_iter().enumerate() {
+                puzzle.0[y][x] = parse_cell::<N>(y, x, ch)?;
+            }
+        }
+
+        puzzle.validate()?;
+        Ok(puzzle)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SolveStep<const N: usize = 3> {
+    pub technique: &'static str,
+    pub idx: CellIndex<N>,
+    pub value: CellValue,
+    pub reason: String,
+}
+
+fn last_possible<const N: usize>(puzzle: &Puzzle<N>) -> Vec<SolveStep<N>> {
+    puzzle
+        .get_empty_cells()
+        .into_iter()
+        .flat_map(|cell| {
+            let possible = cell.get_possible_values();
+
+            if possible.len() != 1 {
+                None
+            } else {
+                let assignment = SolveStep {
+                    technique: "last possible",
+                    idx: cell.idx.clone(),
+                    value: *possible.first().unwrap(),
+                    reason: "every other value is in its row, column or subgrid".to_owned(),
+                };
+                debug!("Assignment from last possible: {:?}", assignment);
+                Some(assignment)
+            }
+        })
+        .collect()
+}
+
+fn last_remaining<const N: usize>(puzzle: &Puzzle<N>) -> Vec<SolveStep<N>> {
+    let mut assignments = vec![];
+    for subgrid in puzzle.get_subgrids() {
+        for value in digits::<N>() {
+            let possible_cells: Vec<Cell<N>> = subgrid
+                .empty_cells()
+                .into_iter()
+                .filter(|cell| cell.get_possible_values().contains(&value))
+                .collect();
+
+            if possible_cells.len() != 1 {
+                continue;
+            }
+
+            if let Some(cell) = possible_cells.first() {
+                let assignment = SolveStep {
+                    technique: "last remaining",
+                    idx: cell.idx.clone(),
+                    value,
+                    reason: format!("only cell left for {} in {}", value, subgrid.idx.name()),
+                };
+                debug!("Assignment from last remaining: {:?}", assignment);
+                assignments.push(assignment);
+            }
+        }
+    }
+    assignments
+}
+
+// Filled cells have no candidate mask, an empty mask marks a dead end
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Candidates<const N: usize = 3>(Vec<Vec<Option<u32>>>);
+
+impl<const N: usize> Candidates<N> {
+    pub fn new(puzzle: &Puzzle<N>) -> Self {
+        let mut candidates = vec![vec![None; N * N]; N * N];
+        for cell in puzzle.get_empty_cells() {
+            candidates[cell.idx.y][cell.idx.x] = Some(
+                cell.get_possible_values()
+                    .into_iter()
+                    .fold(0, |mask, value| mask | 1 << value.digit()),
+            );
+        }
+        Candidates(candidates)
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Vec<CellValue> {
+        digits::<N>()
+            .filter(|&value| self.has(x, y, value.digit()))
+            .collect()
+    }
+
+    pub fn dead_ends(&self) -> Vec<(usize, usize)> {
+        (0..N * N)
+            .flat_map(|y| (0..N * N).map(move |x| (x, y)))
+            .filter(|&(x, y)| self.0[y][x] == Some(0))
+            .collect()
+    }
+
+    fn has(&self, x: usize, y: usize, digit: usize) -> bool {
+        self.0[y][x].is_some_and(|mask| mask & 1 << digit != 0)
+    }
+
+    fn remove(&mut self, x: usize, y: usize, digit: usize) -> bool {
+        let had = self.has(x, y, digit);
+        if let Some(mask) = &mut self.0[y][x] {
+            *mask &= !(1 << digit);
+        }
+        had
+    }
+
+    fn update(&mut self, puzzle: &Puzzle<N>) {
+        let fresh = Candidates::new(puzzle);
+        self.0
+            .iter_mut()
+            .flatten()
+            .zip(fresh.0.iter().flatten())
+            .for_each(|(mask, &fresh)| *mask = mask.zip(fresh).map(|(mask, fresh)| mask & fresh));
+    }
+
+    fn singles(&self, technique: &'static str) -> Vec<SolveStep<N>> {
+        (0..N * N)
+            .flat_map(|y| (0..N * N).map(move |x| (x, y)))
+            .filter_map(|(x, y)| self.0[y][x].map(|mask| (x, y, mask)))
+            .filter(|&(_, _, mask)| mask.count_ones() == 1)
+            .map(|(x, y, mask)| SolveStep {
+                technique,
+                idx: CellIndex { x, y },
+                value: CellValue(mask.trailing_zeros() as u8),
+                reason: format!("only candidate left after {}", technique),
+            })
+            .collect()
+    }
+}
+
+fn units<const N: usize>() -> Vec<Vec<CellIndex<N>>> {
+    (0..N * N)
+        .flat_map(|idx| {
+            [
+                RowIndex::new(idx).cells(),
+                ColumnIndex::new(idx).cells(),
+                SubgridIndex::new(idx).cells(),
+            ]
+        })
+        .collect()
+}
+
+// Two cells in a unit sharing the same two candidates take both values between them,
+// so neither value can appear anywhere else in that unit
+pub fn naked_pairs<const N: usize>(candidates: &mut Candidates<N>) -> usize {
+    naked_subsets(candidates, 2)
+}
+
+// Every way to pick `size` of the first `len` indices, in order
+fn combinations(len: usize, size: usize) -> Vec<Vec<usize>> {
+    if size == 0 {
+        return vec![vec![]];
+    }
+    (size - 1..len)
+        .flat_map(|last| {
+            combinations(last, size - 1)
+                .into_iter()
+                .map(move |mut rest| {
+                    rest.push(last);
+                    rest
+                })
+        })
+        .collect()
+}
+
+// `size` cells in a unit whose candidates only cover `size` values take all of those
+// values between them, so none of them can appear anywhere else in that unit
+fn naked_subsets<const N: usize>(candidates: &mut Candidates<N>, size: usize) -> usize {
+    let mut eliminated = 0;
+    for unit in units::<N>() {
+        let cells: Vec<(&CellIndex<N>, u32)> = unit
+            .iter()
+            .filter_map(|idx| candidates.0[idx.y][idx.x].map(|mask| (idx, mask)))
+            .filter(|(_, mask)| (2..=size as u32).contains(&mask.count_ones()))
+            .collect();
+
+        for subset in combinations(cells.len(), size) {
+            let mask = subset.iter().fold(0u32, |mask, &i| mask | cells[i].1);
+            if mask.count_ones() as usize != size {
+                continue;
+            }
+            let housing: Vec<&CellIndex<N>> = subset.iter().map(|&i| cells[i].0).collect();
+            for idx in unit.iter().filter(|idx| !housing.contains(idx)) {
+                for digit in (1..=N * N).filter(|digit| mask & 1 << digit != 0) {
+                    if candidates.remove(idx.x, idx.y, digit) {
+                        debug!("Naked subset eliminated {} from {:?}", digit, idx);
+                        eliminated += 1;
+                    }
+                }
+            }
+        }
+    }
+    eliminated
+}
+
+pub fn naked_triples<const N: usize>(candidates: &mut Candidates<N>) -> usize {
+    naked_subsets(candidates, 3)
+}
+
+// `size` values in a unit that only fit in the same `size` cells must fill those cells,
+// so every other candidate can be eliminated from them
+fn hidden_subsets<const N: usize>(candidates: &mut Candidates<N>, size: usize) -> usize {
+    let mut eliminated = 0;
+    for unit in units::<N>() {
+        // For each digit, the positions within the unit where it is still a candidate
+        let spots: Vec<(usize, u32)> = (1..=N * N)
+            .map(|digit| {
+                let positions = unit
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, idx)| candidates.has(idx.x, idx.y, digit))
+                    .fold(0u32, |positions, (i, _)| positions | 1 << i);
+                (digit, positions)
+            })
+            .filter(|(_, positions)| (1..=size as u32).contains(&positions.count_ones()))
+            .collect();
+
+        for subset in combinations(spots.len(), size) {
+            let positions = subset
+                .iter()
+                .fold(0u32, |positions, &i| positions | spots[i].1);
+            if positions.count_ones() as usize != size {
+                continue;
+            }
+            let digits = subset
+                .iter()
+                .fold(0u32, |digits, &i| digits | 1 << spots[i].0);
+            for idx in unit
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| positions & 1 << i != 0)
+                .map(|(_, idx)| idx)
+            {
+                for digit in (1..=N * N).filter(|digit| digits & 1 << digit == 0) {
+                    if candidates.remove(idx.x, idx.y, digit) {
+                        debug!("Hidden subset eliminated {} from {:?}", digit, idx);
+                        eliminated += 1;
+                    }
+                }
+            }
+        }
+    }
+    eliminated
+}
+
+pub fn hidden_pairs<const N: usize>(candidates: &mut Candidates<N>) -> usize {
+    hidden_subsets(candidates, 2)
+}
+
+pub fn hidden_triples<const N: usize>(candidates: &mut Candidates<N>) -> usize {
+    hidden_subsets(candidates, 3)
+}
+
+// A digit confined to one row or column of a subgrid must go there, so it can be
+// eliminated from the rest of that row or column
+pub fn pointing_pairs<const N: usize>(candidates: &mut Candidates<N>) -> usize {
+    let mut eliminated = 0;
+    for subgrid in (0..N * N).map(SubgridIndex::<N>::new) {
+        for digit in 1..=N * N {
+            let cells: Vec<CellIndex<N>> = subgrid
+                .cells()
+                .into_iter()
+                .filter(|idx| candidates.has(idx.x, idx.y, digit))
+                .collect();
+            let Some(first) = cells.first() else {
+                continue;
+            };
+
+            let lines = [
+                cells
+                    .iter()
+                    .all(|idx| idx.y == first.y)
+                    .then(|| RowIndex::<N>::new(first.y).cells()),
+                cells
+                    .iter()
+                    .all(|idx| idx.x == first.x)
+                    .then(|| ColumnIndex::<N>::new(first.x).cells()),
+            ];
+            for idx in lines.into_iter().flatten().flatten() {
+                if idx.subgrid() != subgrid.idx && candidates.remove(idx.x, idx.y, digit) {
+                    debug!("Pointing pair eliminated {} from {:?}", digit, idx);
+                    eliminated += 1;
+                }
+            }
+        }
+    }
+    eliminated
+}
+
+// A digit confined to one subgrid within a row or column must go there, so it can be
+// eliminated from the rest of that subgrid
+pub fn box_line_reduction<const N: usize>(candidates: &mut Candidates<N>) -> usize {
+    let mut eliminated = 0;
+    let lines = (0..N * N).flat_map(|idx| {
+        [
+            RowIndex::<N>::new(idx).cells(),
+            ColumnIndex::new(idx).cells(),
+        ]
+    });
+    for line in lines {
+        for digit in 1..=N * N {
+            let subgrids: HashSet<usize> = line
+                .iter()
+                .filter(|idx| candidates.has(idx.x, idx.y, digit))
+                .map(CellIndex::subgrid)
+                .collect();
+            if subgrids.len() != 1 {
+                continue;
+            }
+
+            for idx in subgrids
+                .into_iter()
+                .flat_map(|subgrid| SubgridIndex::<N>::new(subgrid).cells())
+                .filter(|idx| !line.contains(idx))
+            {
+                if candidates.remove(idx.x, idx.y, digit) {
+                    debug!("Box/line reduction eliminated {} from {:?}", digit, idx);
+                    eliminated += 1;
+                }
+            }
+        }
+    }
+    eliminated
+}
+
+// On the diagonal variant placed digits are eliminated along each diagonal, then any
+// diagonal cell with one candidate left, or digit with one cell left, is assigned
+fn diagonals<const N: usize>(
+    candidates: &mut Candidates<N>,
+    puzzle: &Puzzle<N>,
+) -> Vec<SolveStep<N>> {
+    let mut steps = vec![];
+    for diagonal in puzzle.get_diagonals() {
+        let cells = diagonal.idx.cells();
+        for value in diagonal.cells().iter().map(Cell::value) {
+            if value == CellValue::EMPTY {
+                continue;
+            }
+            for idx in &cells {
+                if candidates.remove(idx.x, idx.y, value.digit()) {
+                    debug!("Diagonal eliminated {} from {:?}", value, idx);
+                }
+            }
+        }
+
+        for idx in &cells {
+            if let [value] = candidates.get(idx.x, idx.y)[..] {
+                steps.push(SolveStep {
+                    technique: "diagonal",
+                    idx: idx.clone(),
+                    value,
+                    reason: format!("only candidate left on the {}", diagonal.idx.name()),
+                });
+            }
+        }
+        for value in digits::<N>() {
+            let spots: Vec<&CellIndex<N>> = cells
+                .iter()
+                .filter(|idx| candidates.has(idx.x, idx.y, value.digit()))
+                .collect();
+            if let [idx] = spots[..] {
+                steps.push(SolveStep {
+                    technique: "diagonal",
+                    idx: idx.clone(),
+                    value,
+                    reason: format!(
+                        "only cell left for {} on the {}",
+                        value,
+                        diagonal.idx.name()
+                    ),
+                });
+            }
+        }
+    }
+    steps
+}
+
+// A digit confined to the same `size` positions in `size` rows (or columns) can be
+// eliminated from those positions in every other row (or column)
+fn fish<const N: usize>(candidates: &mut Candidates<N>, size: usize) -> usize {
+    let mut eliminated = 0;
+    for digit in 1..=N * N {
+        for transpose in [false, true] {
+            let cell = |line: usize, pos: usize| if transpose { (line, pos) } else { (pos, line) };
+            let lines: Vec<(usize, u32)> = (0..N * N)
+                .map(|line| {
+                    let positions = (0..N * N)
+                        .filter(|&pos| {
+                            let (x, y) = cell(line, pos);
+                            candidates.has(x, y, digit)
+                        })
+                        .fold(0u32, |mask, pos| mask | 1 << pos);
+                    (line, positions)
+                })
+                .filter(|(_, positions)| (2..=size as u32).contains(&positions.count_ones()))
+                .collect();
+
+            for subset in combinations(lines.len(), size) {
+                let positions = subset.iter().fold(0u32, |mask, &i| mask | lines[i].1);
+                if positions.count_ones() as usize != size {
+                    continue;
+                }
+
+                let fish: Vec<usize> = subset.iter().map(|&i| lines[i].0).collect();
+                for line in (0..N * N).filter(|line| !fish.contains(line)) {
+                    for pos in (0..N * N).filter(|&pos| positions & 1 << pos != 0) {
+                        let (x, y) = cell(line, pos);
+                        if candidates.remove(x, y, digit) {
+                            debug!("Fish of {} eliminated {} from {:?}", size, digit, (x, y));
+                            eliminated += 1;
+                        }
+                    }
+                }
+            }
+        }
+    }
+    eliminated
+}
+
+pub fn x_wing<const N: usize>(candidates: &mut Candidates<N>) -> usize {
+    fish(candidates, 2)
+}
+
+pub fn swordfish<const N: usize>(candidates: &mut Candidates<N>) -> usize {
+    fish(candidates, 3)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+    Expert,
+}
+
+pub struct Solver<const N: usize = 3> {
+    pub puzzle: Puzzle<N>,
+    candidates: Candidates<N>,
+    solution: Option<Puzzle<N>>,
+    difficulty: Difficulty,
+    steps: Option<Vec<SolveStep<N>>>,
+    variant: Variant,
+}
+
+impl<const N: usize> Solver<N> {
+    pub fn from(puzzle: Puzzle<N>) -> Self {
+        Solver {
+            candidates: Candidates::new(&puzzle),
+            puzzle,
+            solution: None,
+            difficulty: Difficulty::Easy,
+            steps: None,
+            variant: Variant::Standard,
+        }
+    }
+
+    pub fn with_solution(mut self, solution: Puzzle<N>) -> Self {
+        self.solution = Some(solution);
+        self
+    }
+
+    pub fn with_variant(mut self, variant: Variant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    pub fn with_steps(mut self) -> Self {
+        self.steps = Some(Vec::new());
+        self
+    }
+
+    // Empty unless the solver was built with_steps
+    pub fn steps(&self) -> &[SolveStep<N>] {
+        self.steps.as_deref().unwrap_or_default()
+    }
+
+    fn check_invariants(&self, assignments: &[SolveStep<N>]) {
+        let Some(solution) = &self.solution else {
+            return;
+        };
+
+        for assignment in assignments {
+            let expected = solution.0[assignment.idx.y][assignment.idx.x];
+            debug_assert_eq!(
+                assignment.value, expected,
+                "Wrong assignment {:?}",
+                assignment
+            );
+        }
+        for cell in self.puzzle.get_empty_cells() {
+            let expected = solution.0[cell.idx.y][cell.idx.x];
+            debug_assert!(
+                cell.get_possible_values().contains(&expected),
+                "Eliminated {} from {:?}",
+                expected,
+                cell.idx
+            );
+            debug_assert!(
+                self.candidates
+                    .has(cell.idx.x, cell.idx.y, expected.digit()),
+                "Eliminated candidate {} from {:?}",
+                expected,
+                cell.idx
+            );
+        }
+    }
+
+    pub fn solve(&mut self) -> Result<(), PuzzleError> {
+        self.apply_rules();
+        if self.puzzle.is_complete_for(self.variant) {
+            return Ok(());
+        }
+
+        info!("Logical rules stalled, backtracking");
+        self.difficulty = Difficulty::Expert;
+        let stalled = self.puzzle.clone();
+        if self.puzzle.is_valid_for(self.variant) && backtrack(&mut self.puzzle, self.variant) {
+            if let Some(steps) = &mut self.steps {
+                steps.extend(stalled.get_empty_cells().into_iter().map(|cell| SolveStep {
+                    technique: "backtracking",
+                    value: self.puzzle.0[cell.idx.y][cell.idx.x],
+                    idx: cell.idx,
+                    reason: "found by search".to_owned(),
+                }));
+            }
+            Ok(())
+        } else {
+            Err(PuzzleError::Unsolvable)
+        }
+    }
+
+    // The hardest technique that made progress so far
+    pub fn difficulty(&self) -> Difficulty {
+        self.difficulty
+    }
+
+    fn used(&mut self, difficulty: Difficulty, eliminated: usize, assignments: &[SolveStep<N>]) {
+        if eliminated > 0 || !assignments.is_empty() {
+            self.difficulty = self.difficulty.max(difficulty);
+        }
+    }
+
+    fn apply_rules(&mut self) {
+        let mut change = true;
+        while change {
+            change = false;
+            self.candidates.update(&self.puzzle);
+            let mut assignments: Vec<SolveStep<N>> =
+                vec![last_possible(&self.puzzle), last_remaining(&self.puzzle)]
+                    .into_iter()
+                    .flatten()
+                    .collect();
+            if self.variant == Variant::Diagonal {
+                assignments.extend(diagonals(&mut self.candidates, &self.puzzle));
+            }
+
+            if assignments.is_empty() {
+                let eliminated = naked_pairs(&mut self.candidates);
+                info!("Number of naked pair eliminations: {}", eliminated);
+                change |= eliminated > 0;
+                assignments = self.candidates.singles("naked pairs");
+                self.used(Difficulty::Medium, eliminated, &assignments);
+            }
+
+            if assignments.is_empty() {
+                let eliminated = naked_triples(&mut self.candidates);
+                info!("Number of naked triple eliminations: {}", eliminated);
+                change |= eliminated > 0;
+                assignments = self.candidates.singles("naked triples");
+                self.used(Difficulty::Medium, eliminated, &assignments);
+            }
+
+            if assignments.is_empty() {
+                let eliminated =
+                    pointing_pairs(&mut self.candidates) + box_line_reduction(&mut self.candidates);
+                info!("Number of box/line eliminations: {}", eliminated);
+                change |= eliminated > 0;
+                assignments = self.candidates.singles("box/line reduction");
+                self.used(Difficulty::Hard, eliminated, &assignments);
+            }
+
+            if assignments.is_empty() {
+                let eliminated =
+                    hidden_pairs(&mut self.candidates) + hidden_triples(&mut self.candidates);
+                info!("Number of hidden subset eliminations: {}", eliminated);
+                change |= eliminated > 0;
+                assignments = self.candidates.singles("hidden subsets");
+                self.used(Difficulty::Hard, eliminated, &assignments);
+            }
+
+            if assignments.is_empty() {
+                let eliminated = x_wing(&mut self.candidates);
+                info!("Number of X-Wing eliminations: {}", eliminated);
+                change |= eliminated > 0;
+                assignments = self.candidates.singles("x-wing");
+                self.used(Difficulty::Hard, eliminated, &assignments);
+            }
+
+            if assignments.is_empty() {
+                let eliminated = swordfish(&mut self.candidates);
+                info!("Number of Swordfish eliminations: {}", eliminated);
+                change |= eliminated > 0;
+                assignments = self.candidates.singles("swordfish");
+                self.used(Difficulty::Hard, eliminated, &assignments);
+            }
+
+            info!("Number of Assignments: {}", assignments.len());
+            if cfg!(debug_assertions) {
+                self.check_invariants(&assignments);
+            }
+
+            change |= !assignments.is_empty();
+
+            for assignment in assignments {
+                // Both singles rules can find the same cell
+                if self.puzzle.0[assignment.idx.y][assignment.idx.x] != CellValue::EMPTY {
+                    continue;
+                }
+                self.puzzle
+                    .set_cell(assignment.idx.clone(), assignment.value);
+                if let Some(steps) = &mut self.steps {
+                    steps.push(assignment);
+                }
+            }
+        }
+    }
+}
+
+pub struct Generator {
+    rng: StdRng,
+}
+
+impl Generator {
+    pub fn new(seed: u64) -> Self {
+        Generator {
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    pub fn generate(&mut self, clues: usize) -> Puzzle {
+        let mut puzzle = Puzzle::empty();
+        fill(&mut puzzle, &mut self.rng);
+
+        let mut cells: Vec<(usize, usize)> = (0..81).map(|idx| (idx % 9, idx / 9)).collect();
+        cells.shuffle(&mut self.rng);
+        let mut givens = 81;
+        for (x, y) in cells {
+            if givens <= clues {
+                break;
+            }
+            let value = puzzle.0[y][x];
+            puzzle.0[y][x] = CellValue::EMPTY;
+            if puzzle.solution_count() == 1 {
+                givens -= 1;
+            } else {
+                puzzle.0[y][x] = value;
+            }
+        }
+        debug!("Generated puzzle with {} givens", givens);
+        puzzle
+    }
+}
+
+pub fn solve_line(line: &str) -> Result<String, PuzzleError> {
+    let mut solver = Solver::from(Puzzle::<3>::from_line(line.trim_matches(['\r', '\n']))?);
+    solver.solve()?;
+
+    Ok(solver.puzzle.to_line())
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn solve_sudoku(line: &str) -> Result<String, wasm_bindgen::JsValue> {
+    solve_line(line).map_err(|err| wasm_bindgen::JsValue::from_str(&err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod grid {
+        use super::*;
+
+        #[test]
+        fn test_rows() {
+            let puzzle: Puzzle = include_str!("puzzles/easy/1/input.txt").parse().unwrap();
+
+            assert_eq!(
+                puzzle
+                    .get_rows()
+                    .into_iter()
+                    .map(|row| row.cells().into_iter().map(|cell| cell.value()).collect())
+                    .collect::<Vec<Vec<CellValue>>>(),
+                vec![
+                    vec![
+                        CellValue::EIGHT,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::FIVE,
+                        CellValue::EMPTY,
+                        CellValue::FOUR,
+                        CellValue::SEVEN,
+                        CellValue::EMPTY,
+                        CellValue::TWO
+                    ],
+                    vec![
+                        CellValue::NINE,
+                        CellValue::THREE,
+                        CellValue::TWO,
+                        CellValue::SEVEN,
+                        CellValue::EMPTY,
+                        CellValue::EIGHT,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY
+                    ],
+                    vec![
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::ONE,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::NINE,
+                        CellValue::EIGHT,
+                        CellValue::EMPTY
+                    ],
+                    vec![
+                        CellValue::EMPTY,
+                        CellValue::FIVE,
+                        CellValue::FOUR,
+                        CellValue::THREE,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY
+                    ],
+                    vec![
+                        CellValue::EMPTY,
+                        CellValue::TWO,
+                        CellValue::EMPTY,
+                        CellValue::SIX,
+                        CellValue::EMPTY,
+                        CellValue::NINE,
+                        CellValue::EMPTY,
+                        CellValue::FIVE,
+                        CellValue::EMPTY
+                    ],
+                    vec![
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::FIVE,
+                        CellValue::EIGHT,
+                        CellValue::FOUR,
+                        CellValue::EMPTY
+                    ],
+                    vec![
+                        CellValue::EMPTY,
+                        CellValue::ONE,
+                        CellValue::THREE,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::SIX,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY
+                    ],
+                    vec![
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::FOUR,
+                        CellValue::EMPTY,
+                        CellValue::TWO,
+                        CellValue::SIX,
+                        CellValue::NINE,
+                        CellValue::THREE
+                    ],
+                    vec![
+                        CellValue::SIX,
+                        CellValue::EMPTY,
+                        CellValue::NINE,
+                        CellValue::EIGHT,
+                        CellValue::EMPTY,
+                        CellValue::SEVEN,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::FIVE
+                    ],
+                ]
+            )
+        }
+
+        #[test]
+        fn test_cols() {
+            let puzzle: Puzzle = include_str!("puzzles/easy/1/input.txt").parse().unwrap();
+
+            assert_eq!(
+                puzzle
+                    .get_cols()
+                    .into_iter()
+                    .map(|row| row.cells().into_iter().map(|cell| cell.value()).collect())
+                    .collect::<Vec<Vec<CellValue>>>(),
+                vec![
+                    vec![
+                        CellValue::EIGHT,
+                        CellValue::NINE,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::SIX
+                    ],
+                    vec![
+                        CellValue::EMPTY,
+                        CellValue::THREE,
+                        CellValue::EMPTY,
+                        CellValue::FIVE,
+                        CellValue::TWO,
+                        CellValue::EMPTY,
+                        CellValue::ONE,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY
+                    ],
+                    vec![
+                        CellValue::EMPTY,
+                        CellValue::TWO,
+                        CellValue::EMPTY,
+                        CellValue::FOUR,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::THREE,
+                        CellValue::EMPTY,
+                        CellValue::NINE
+                    ],
+                    vec![
+                        CellValue::FIVE,
+                        CellValue::SEVEN,
+                        CellValue::ONE,
+                        CellValue::THREE,
+                        CellValue::SIX,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::FOUR,
+                        CellValue::EIGHT
+                    ],
+                    vec![
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY
+                    ],
+                    vec![
+                        CellValue::FOUR,
+                        CellValue::EIGHT,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::NINE,
+                        CellValue::FIVE,
+                        CellValue::SIX,
+                        CellValue::TWO,
+                        CellValue::SEVEN
+                    ],
+                    vec![
+                        CellValue::SEVEN,
+                        CellValue::EMPTY,
+                        CellValue::NINE,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EIGHT,
+                        CellValue::EMPTY,
+                        CellValue::SIX,
+                        CellValue::EMPTY
+                    ],
+                    vec![
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EIGHT,
+                        CellValue::EMPTY,
+                        CellValue::FIVE,
+                        CellValue::FOUR,
+                        CellValue::EMPTY,
+                        CellValue::NINE,
+                        CellValue::EMPTY
+                    ],
+                    vec![
+                        CellValue::TWO,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::THREE,
+                        CellValue::FIVE
+                    ],
+                ]
+            )
+        }
+
+        #[test]
+        fn test_sub_grids() {
+            let puzzle: Puzzle = include_str!("puzzles/easy/1/input.txt").parse().unwrap();
+
+            assert_eq!(
+                puzzle
+                    .get_subgrids()
+                    .into_iter()
+                    .map(|row| row.cells().into_iter().map(|cell| cell.value()).collect())
+                    .collect::<Vec<Vec<CellValue>>>(),
+                vec![
+                    vec![
+                        CellValue::EIGHT,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::NINE,
+                        CellValue::THREE,
+                        CellValue::TWO,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY
+                    ],
+                    vec![
+                        CellValue::FIVE,
+                        CellValue::EMPTY,
+                        CellValue::FOUR,
+                        CellValue::SEVEN,
+                        CellValue::EMPTY,
+                        CellValue::EIGHT,
+                        CellValue::ONE,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY
+                    ],
+                    vec![
+                        CellValue::SEVEN,
+                        CellValue::EMPTY,
+                        CellValue::TWO,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::NINE,
+                        CellValue::EIGHT,
+                        CellValue::EMPTY
+                    ],
+                    vec![
+                        CellValue::EMPTY,
+                        CellValue::FIVE,
+                        CellValue::FOUR,
+                        CellValue::EMPTY,
+                        CellValue::TWO,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY
+                    ],
+                    vec![
+                        CellValue::THREE,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::SIX,
+                        CellValue::EMPTY,
+                        CellValue::NINE,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::FIVE
+                    ],
+                    vec![
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::FIVE,
+                        CellValue::EMPTY,
+                        CellValue::EIGHT,
+                        CellValue::FOUR,
+                        CellValue::EMPTY
+                    ],
+                    vec![
+                        CellValue::EMPTY,
+                        CellValue::ONE,
+                        CellValue::THREE,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::SIX,
+                        CellValue::EMPTY,
+                        CellValue::NINE
+                    ],
+                    vec![
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::SIX,
+                        CellValue::FOUR,
+                        CellValue::EMPTY,
+                        CellValue::TWO,
+                        CellValue::EIGHT,
+                        CellValue::EMPTY,
+                        CellValue::SEVEN
+                    ],
+                    vec![
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::SIX,
+                        CellValue::NINE,
+                        CellValue::THREE,
+                        CellValue::EMPTY,
+                        CellValue::EMPTY,
+                        CellValue::FIVE
+                    ],
+                ]
+            )
+        }
+    }
+
+    mod parse {
+        use super::*;
+
+        fn rows(puzzle: &Puzzle) -> Vec<String> {
+            puzzle
+                .0
+                .iter()
+                .map(|row| row.iter().map(|value| value.to_string()).collect())
+                .collect()
+        }
+
+        #[test]
+        fn test_parse() {
+            let puzzle: Puzzle = include_str!("puzzles/easy/1/input.txt").parse().unwrap();
+
+            assert_eq!(rows(&puzzle)[0], "8--5-47-2");
+            assert_eq!(rows(&puzzle)[8], "6-98-7--5");
+        }
+
+        #[test]
+        fn test_wrong_col_count() {
+            let solution = include_str!("puzzles/easy/1/output.txt");
+            let long: String = solution
+                .lines()
+                .map(|row| format!("{}xyz\n", row))
+                .collect();
+            let short: Vec<&str> = solution.lines().take(8).collect();
+
+            assert_eq!(
+                long.parse::<Puzzle>(),
+                Err(ParseError::WrongColCount {
+                    row: 1,
+                    expected: 9,
+                    found: 12
+                })
+            );
+            assert_eq!(
+                format!("{}\n123", short.join("\n")).parse::<Puzzle>(),
+                Err(ParseError::WrongColCount {
+                    row: 9,
+                    expected: 9,
+                    found: 3
+                })
+            );
+        }
+
+        #[test]
+        fn test_invalid_char() {
+            let input = include_str!("puzzles/easy/1/input.txt").replacen("-98", "x98", 1);
+
+            assert_eq!(
+                input.parse::<Puzzle>(),
+                Err(ParseError::InvalidChar {
+                    row: 3,
+                    col: 6,
+                    ch: 'x'
+                })
+            );
+            assert_eq!(
+                format!("{}a", ".".repeat(80)).parse::<Puzzle>(),
+                Err(ParseError::InvalidChar {
+                    row: 9,
+                    col: 9,
+                    ch: 'a'
+                })
+            );
+            assert_eq!(
+                ParseError::InvalidChar {
+                    row: 3,
+                    col: 6,
+                    ch: 'x'
+                }
+                .to_string(),
+                "Unexpected 'x' in row 3, column 6"
+            );
+        }
+
+        #[test]
+        fn test_blank_lines_skipped() {
+            let input = include_str!("puzzles/easy/1/input.txt");
+            let rows: Vec<&str> = input.lines().collect();
+            let spaced = format!(
+                "{}\n\n{}\n\n{}\n",
+                rows[..3].join("\n"),
+                rows[3..6].join("\n"),
+                rows[6..].join("\n")
+            );
+
+            assert_eq!(spaced.parse::<Puzzle>(), input.parse::<Puzzle>());
+        }
+
+        #[test]
+        fn test_single_line() {
+            let line = "8..5.47.2932708000...1  98.";
+            let line = format!(
+                "{}{}",
+                line, ".54300000.2.609.5......584..13..6......4.2693609807..5"
+            );
+            let puzzle: Puzzle = line.parse().unwrap();
+
+            assert_eq!(
+                puzzle,
+                include_str!("puzzles/easy/1/input.txt").parse().unwrap()
+            );
+            assert_eq!(puzzle.to_line(), line.replace(['.', ' '], "0"));
+            assert_eq!(Puzzle::from_line(&puzzle.to_line()).unwrap(), puzzle);
+            assert_eq!(format!("{}\n", line).parse::<Puzzle>().unwrap(), puzzle);
+        }
+
+        #[test]
+        fn test_single_line_length() {
+            let line = ".".repeat(81);
+
+            assert!("".parse::<Puzzle>().is_err());
+            assert!(line[1..].parse::<Puzzle>().is_err());
+            assert!(format!("{}.", line).parse::<Puzzle>().is_err());
+            assert_eq!(
+                Puzzle::<3>::from_line(&line[9..]),
+                Err(ParseError::WrongLength {
+                    expected: 81,
+                    found: 72
+                })
+            );
+        }
+
+        #[test]
+        fn test_duplicates() {
+            let error = |input: String| match input.parse::<Puzzle>() {
+                Err(err @ ParseError::Duplicate { .. }) => err.to_string(),
+                other => panic!("Expected a duplicate, got {:?}", other),
+            };
+            let blank = "---------\n";
+
+            assert_eq!(
+                error(format!("{}5---5----\n{}", blank.repeat(2), blank.repeat(6))),
+                "duplicate 5 in row 3"
+            );
+            assert_eq!(
+                error(format!(
+                    "-7-------\n{}-7-------\n{}",
+                    blank.repeat(4),
+                    blank.repeat(3)
+                )),
+                "duplicate 7 in column 2"
+            );
+            assert_eq!(
+                error(format!(
+                    "{}---9-----\n----9----\n{}",
+                    blank.repeat(3),
+                    blank.repeat(4)
+                )),
+                "duplicate 9 in subgrid 5"
+            );
+            assert_eq!(
+                error(format!("33{}", ".".repeat(79))),
+                "duplicate 3 in row 1"
+            );
+        }
+
+        #[test]
+        fn test_too_few_rows() {
+            assert_eq!(
+                "123456789\n".repeat(8).parse::<Puzzle>(),
+                Err(ParseError::WrongRowCount {
+                    expected: 9,
+                    found: 8
+                })
+            );
+            assert_eq!(
+                ParseError::WrongRowCount {
+                    expected: 9,
+                    found: 8
+                }
+                .to_string(),
+                "Expected 9 rows, found 8"
+            );
+        }
+    }
+    mod line {
+        use super::*;
+
+        #[test]
+        fn test_solve_line() {
+            let line = include_str!("puzzles/easy/1/input.txt")
+                .lines()
+                .collect::<String>();
+            let expected = include_str!("puzzles/easy/1/output.txt")
+                .lines()
+                .collect::<String>();
+
+            assert_eq!(solve_line(&line).unwrap(), expected);
+        }
+
+        #[test]
+        fn test_solve_line_space_blanks() {
+            let line = include_str!("puzzles/hard/1/input.txt")
+                .lines()
+                .collect::<String>()
+                .replace('-', " ");
+            let expected = include_str!("puzzles/hard/1/output.txt")
+                .lines()
+                .collect::<String>();
+
+            assert!(line.ends_with(' '));
+            assert_eq!(solve_line(&format!("{}\r\n", line)).unwrap(), expected);
+        }
+
+        #[test]
+        fn test_solve_line_wrong_length() {
+            assert!(matches!(solve_line("123"), Err(PuzzleError::Parse { .. })));
+        }
+    }
+
+    mod steps {
+        use super::*;
+
+        #[test]
+        fn test_easy_steps() {
+            let puzzle: Puzzle = include_str!("puzzles/easy/1/input.txt").parse().unwrap();
+            let solution: Puzzle = include_str!("puzzles/easy/1/output.txt").parse().unwrap();
+            let mut solver = Solver::from(puzzle.clone()).with_steps();
+            solver.solve().unwrap();
+
+            let steps = solver.steps();
+            assert_eq!(steps.len(), puzzle.get_empty_cells().len());
+            assert_eq!(
+                steps[..2]
+                    .iter()
+                    .map(|step| (step.technique, step.idx.x(), step.idx.y(), step.value))
+                    .collect::<Vec<_>>(),
+                vec![
+                    ("last possible", 1, 0, CellValue::SIX),
+                    ("last possible", 4, 1, CellValue::SIX)
+                ]
+            );
+            for step in steps {
+                assert_eq!(step.value, solution.0[step.idx.y][step.idx.x]);
+                assert!(["last possible", "last remaining"].contains(&step.technique));
+            }
+        }
+
+        #[test]
+        fn test_steps_off_by_default() {
+            let puzzle: Puzzle = include_str!("puzzles/easy/1/input.txt").parse().unwrap();
+            let mut solver = Solver::from(puzzle);
+            solver.solve().unwrap();
+
+            assert!(solver.steps().is_empty());
+        }
+
+        #[test]
+        fn test_backtracking_steps() {
+            let puzzle: Puzzle = include_str!("puzzles/hard/1/input.txt").parse().unwrap();
+            let mut solver = Solver::from(puzzle.clone()).with_steps();
+            solver.solve().unwrap();
+
+            assert_eq!(solver.steps().len(), puzzle.get_empty_cells().len());
+            assert_eq!(solver.steps().last().unwrap().technique, "backtracking");
+        }
+    }
+
+    mod diagonal {
+        use super::*;
+
+        #[test]
+        fn test_cells() {
+            let cells = DiagonalIndex::<3>::new(1).cells();
+
+            assert_eq!(cells.first(), Some(&CellIndex { x: 8, y: 0 }));
+            assert_eq!(cells.last(), Some(&CellIndex { x: 0, y: 8 }));
+            assert!(cells.contains(&CellIndex { x: 4, y: 4 }));
+        }
+
+        #[test]
+        fn test_valid() {
+            let diagonal: Puzzle = include_str!("puzzles/diagonal/1/output.txt")
+                .parse()
+                .unwrap();
+            let standard: Puzzle = include_str!("puzzles/easy/1/output.txt").parse().unwrap();
+
+            assert!(diagonal.is_complete_for(Variant::Diagonal));
+            assert!(standard.is_complete_for(Variant::Standard));
+            assert!(!standard.is_valid_for(Variant::Diagonal));
+            assert!(!standard.is_complete_for(Variant::Diagonal));
+        }
+
+        #[test]
+        fn test_solve() {
+            let puzzle: Puzzle = include_str!("puzzles/diagonal/1/input.txt")
+                .parse()
+                .unwrap();
+            let solution: Puzzle = include_str!("puzzles/diagonal/1/output.txt")
+                .parse()
+                .unwrap();
+            let mut solver = Solver::from(puzzle.clone())
+                .with_variant(Variant::Diagonal)
+                .with_solution(solution.clone());
+            solver.solve().unwrap();
+
+            // The diagonals are needed to pin down a single answer
+            assert_eq!(puzzle.solution_count(), 2);
+            assert_eq!(puzzle.solution_count_for(Variant::Diagonal), 1);
+            assert_eq!(solver.puzzle, solution);
+        }
+
+        #[test]
+        fn test_eliminations() {
+            let puzzle: Puzzle = include_str!("puzzles/diagonal/1/input.txt")
+                .parse()
+                .unwrap();
+            let mut candidates = puzzle.candidates();
+            assert!(candidates.has(4, 4, 2));
+
+            diagonals(&mut candidates, &puzzle);
+            // 2 is already on the anti-diagonal, 1 stays possible
+            assert!(!candidates.has(4, 4, 2));
+            assert!(candidates.has(4, 4, 1));
+        }
+    }
+
+    mod conflicts {
+        use super::*;
+
+        fn idx(x: usize, y: usize) -> CellIndex {
+            CellIndex::new(x, y).unwrap()
+        }
+
+        #[test]
+        fn test_broken_board() {
+            let mut puzzle = Puzzle::<3>::empty();
+            // Same row and subgrid, reported once
+            puzzle.0[0][0] = CellValue::FIVE;
+            puzzle.0[0][2] = CellValue::FIVE;
+            // Same column as the first 5
+            puzzle.0[6][0] = CellValue::FIVE;
+            // Same subgrid only
+            puzzle.0[3][3] = CellValue::SEVEN;
+            puzzle.0[5][4] = CellValue::SEVEN;
+            // No conflict
+            puzzle.0[8][8] = CellValue::FIVE;
+
+            assert_eq!(
+                puzzle.conflicts(),
+                vec![
+                    (idx(0, 0), idx(2, 0)),
+                    (idx(0, 0), idx(0, 6)),
+                    (idx(3, 3), idx(4, 5)),
+                ]
+            );
+            assert!(!puzzle.is_valid());
+        }
+
+        #[test]
+        fn test_valid_board() {
+            let puzzle: Puzzle = include_str!("puzzles/easy/1/output.txt").parse().unwrap();
+
+            assert!(puzzle.conflicts().is_empty());
+        }
+    }
+
+    mod hint {
+        use super::*;
+
+        #[test]
+        fn test_mid_solve() {
+            let mut puzzle: Puzzle = include_str!("puzzles/easy/1/input.txt").parse().unwrap();
+            let solution: Puzzle = include_str!("puzzles/easy/1/output.txt").parse().unwrap();
+            let mut solver = Solver::from(puzzle.clone()).with_steps();
+            solver.solve().unwrap();
+            for step in &solver.steps()[..10] {
+                puzzle.set_cell(step.idx.clone(), step.value);
+            }
+            let before = puzzle.clone();
+
+            let (idx, value, technique) = puzzle.hint().unwrap();
+            assert_eq!(puzzle, before);
+            assert_eq!(puzzle.0[idx.y()][idx.x()], CellValue::EMPTY);
+            assert_eq!(value, solution.0[idx.y()][idx.x()]);
+            assert_eq!(technique, "last possible");
+        }
+
+        #[test]
+        fn test_advanced() {
+            let mut puzzle: Puzzle = include_str!("puzzles/medium/1/input.txt").parse().unwrap();
+            let mut solver = Solver::from(puzzle.clone()).with_steps();
+            solver.solve().unwrap();
+            // Play the singles up to where the solver first needed more
+            for step in solver
+                .steps()
+                .iter()
+                .take_while(|step| step.technique.starts_with("last"))
+            {
+                puzzle.set_cell(step.idx.clone(), step.value);
+            }
+
+            let (idx, value, technique) = puzzle.hint().unwrap();
+            assert_eq!(value, solver.puzzle.0[idx.y()][idx.x()]);
+            assert!(!technique.starts_with("last"));
+        }
+
+        #[test]
+        fn test_complete() {
+            let puzzle: Puzzle = include_str!("puzzles/easy/1/output.txt").parse().unwrap();
+
+            assert_eq!(puzzle.hint(), None);
+        }
+    }
+
+    mod difficulty {
+        use super::*;
+
+        fn rate(input: &str) -> Difficulty {
+            input.parse::<Puzzle>().unwrap().difficulty()
+        }
+
+        #[test]
+        fn test_easy() {
+            assert_eq!(
+                rate(include_str!("puzzles/easy/1/input.txt")),
+                Difficulty::Easy
+            );
+            assert_eq!(
+                rate(include_str!("puzzles/easy/2/input.txt")),
+                Difficulty::Easy
+            );
+        }
+
+        #[test]
+        fn test_harder() {
+            assert_eq!(
+                rate(include_str!("puzzles/medium/1/input.txt")),
+                Difficulty::Hard
+            );
+            assert_eq!(
+                rate(include_str!("puzzles/hard/1/input.txt")),
+                Difficulty::Expert
+            );
+        }
+
+        #[test]
+        fn test_fish_below_backtracking() {
+            let swordfish = rate(include_str!("puzzles/swordfish/2/input.txt"));
+
+            assert_eq!(swordfish, Difficulty::Hard);
+            assert!(swordfish < rate(include_str!("puzzles/hard/1/input.txt")));
+        }
+
+        #[test]
+        fn test_complete() {
+            assert_eq!(
+                rate(include_str!("puzzles/easy/1/output.txt")),
+                Difficulty::Easy
+            );
+        }
+    }
+
+    mod generator {
+        use super::*;
+
+        fn givens(puzzle: &Puzzle) -> usize {
+            puzzle
+                .0
+                .iter()
+                .flatten()
+                .filter(|value| **value != CellValue::EMPTY)
+                .count()
+        }
+
+        #[test]
+        fn test_generate() {
+            let puzzle = Generator::new(7).generate(30);
+            let parsed: Puzzle = puzzle.to_line().parse().unwrap();
+
+            assert_eq!(parsed, puzzle);
+            assert!(puzzle.is_valid());
+            assert_eq!(puzzle.solution_count(), 1);
+            assert_eq!(givens(&puzzle), 30);
+        }
+
+        #[test]
+        fn test_generate_too_few_clues() {
+            let puzzle = Generator::new(7).generate(0);
+
+            assert_eq!(puzzle.solution_count(), 1);
+            assert!(givens(&puzzle) >= 17);
+        }
+
+        #[test]
+        fn test_seeded() {
+            assert_eq!(
+                Generator::new(1).generate(40),
+                Generator::new(1).generate(40)
+            );
+            assert_ne!(
+                Generator::new(1).generate(40),
+                Generator::new(2).generate(40)
+            );
+        }
+    }
+
+    mod index {
+        use super::*;
+
+        #[test]
+        fn test_out_of_bounds() {
+            assert!(matches!(
+                CellIndex::<3>::new(9, 0),
+                Err(PuzzleError::OutOfBounds { x: 9, y: 0 })
+            ));
+            assert!(CellIndex::<3>::new(0, 9).is_err());
+        }
+
+        #[test]
+        fn test_get() {
+            let puzzle: Puzzle = include_str!("puzzles/easy/1/input.txt").parse().unwrap();
+
+            assert_eq!(puzzle.get(0, 0).unwrap(), CellValue::EIGHT);
+            assert_eq!(puzzle.get(1, 0).unwrap(), CellValue::EMPTY);
+            assert_eq!(puzzle.get(3, 1).unwrap(), CellValue::SEVEN);
+            assert!(matches!(
+                puzzle.get(0, 9),
+                Err(PuzzleError::OutOfBounds { x: 0, y: 9 })
+            ));
+        }
+    }
+    mod properties {
+        use super::*;
+        use proptest::prelude::*;
+
+        fn value(digit: usize) -> CellValue {
+            CellValue(digit as u8)
+        }
+
+        fn digit(value: CellValue) -> usize {
+            value.digit()
+        }
+
+        // Relabelling digits, rotating bands and stacks, and transposing all keep a grid valid
+        fn solutions() -> impl Strategy<Value = Puzzle> {
+            let digits: Vec<usize> = (1..=9).collect();
+            (
+                Just(digits).prop_shuffle(),
+                0..3usize,
+                0..3usize,
+                any::<bool>(),
+            )
+                .prop_map(|(digits, band, stack, transpose)| {
+                    let solved: Puzzle = include_str!("puzzles/easy/1/output.txt").parse().unwrap();
+                    let mut grid = vec![vec![CellValue::EMPTY; 9]; 9];
+                    for (y, row) in grid.iter_mut().enumerate() {
+                        for (x, cell) in row.iter_mut().enumerate() {
+                            let (sy, sx) = if transpose { (x, y) } else { (y, x) };
+                            let original = solved.0[(sy + band * 3) % 9][(sx + stack * 3) % 9];
+                            *cell = value(digits[digit(original) - 1]);
+                        }
+                    }
+                    Puzzle(grid)
+                })
+        }
+
+        // Shrinks towards fewer blanks, so failures reduce to the smallest puzzle that breaks
+        fn puzzles() -> impl Strategy<Value = (Puzzle, Puzzle)> {
+            (solutions(), prop::collection::vec(any::<bool>(), 81)).prop_map(
+                |(solution, blanks)| {
+                    let mut puzzle = solution.clone();
+                    for (idx, blank) in blanks.into_iter().enumerate() {
+                        if blank {
+                            puzzle.0[idx / 9][idx % 9] = CellValue::EMPTY;
+                        }
+                    }
+                    (puzzle, solution)
+                },
+            )
+        }
+
+        proptest! {
+            // Every case runs the full solver in a debug build
+            #![proptest_config(ProptestConfig::with_cases(64))]
+
+            #[test]
+            fn test_line_round_trip(digits in prop::collection::vec(0..=9usize, 81)) {
+                let mut puzzle = Puzzle::<3>::empty();
+                for (idx, digit) in digits.into_iter().enumerate() {
+                    puzzle.0[idx / 9][idx % 9] = value(digit);
+                }
+
+                prop_assert_eq!(puzzle.to_line().len(), 81);
+                prop_assert_eq!(Puzzle::from_line(&puzzle.to_line()).unwrap(), puzzle);
+            }
+
+            #[test]
+            fn test_generated_solutions_are_valid(solution in solutions()) {
+                prop_assert!(solution.is_valid());
+                prop_assert!(solution.is_complete());
+            }
+
+            #[test]
+            fn test_assignments_match_solution((puzzle, solution) in puzzles()) {
+                let mut solver = Solver::from(puzzle.clone()).with_solution(solution.clone());
+                solver.apply_rules();
+
+                prop_assert!(solver.puzzle.is_valid());
+                for (y, row) in solver.puzzle.0.iter().enumerate() {
+                    for (x, &value) in row.iter().enumerate() {
+                        if value != CellValue::EMPTY {
+                            prop_assert_eq!(value, solution.0[y][x]);
+                        }
+                        if puzzle.0[y][x] != CellValue::EMPTY {
+                            prop_assert_eq!(value, puzzle.0[y][x]);
+                        }
+                    }
+                }
+            }
+
+            #[test]
+            fn test_backtracking_completes((puzzle, _) in puzzles()) {
+                let mut solver = Solver::from(puzzle.clone());
+
+                prop_assert!(solver.solve().is_ok());
+                prop_assert!(solver.puzzle.is_valid());
+                prop_assert!(solver.puzzle.is_complete());
+                for (y, row) in puzzle.0.iter().enumerate() {
+                    for (x, &value) in row.iter().enumerate() {
+                        if value != CellValue::EMPTY {
+                            prop_assert_eq!(solver.puzzle.0[y][x], value);
+                        }
+                    }
+                }
+            }
+        }
+
+        #[test]
+        #[should_panic(expected = "Eliminated")]
+        #[cfg(debug_assertions)]
+        fn test_invariant_check() {
+            let solution: Puzzle = include_str!("puzzles/easy/1/output.txt").parse().unwrap();
+            let mut wrong = solution.clone();
+            wrong.0[0] = vec![CellValue::EMPTY; 9];
+            wrong.0[0][0] = CellValue::ONE;
+
+            Solver::from(wrong).with_solution(solution).apply_rules();
+        }
+    }
+
+    mod swordfish {
+        use super::*;
+
+        #[test]
+        fn test_fixture() {
+            let puzzle: Puzzle = include_str!("puzzles/swordfish/1/input.txt")
+                .parse()
+                .unwrap();
+            assert!(last_possible(&puzzle).is_empty());
+            assert!(last_remaining(&puzzle).is_empty());
+
+            let mut candidates = Candidates::new(&puzzle);
+            let fish = [(3, 0), (5, 0), (3, 3), (5, 5), (8, 3), (8, 5)];
+            let targets = [(6, 0), (6, 3), (6, 5)];
+            assert!(targets.iter().all(|&(x, y)| candidates.has(x, y, 3)));
+
+            assert_eq!(swordfish(&mut candidates), 3);
+            assert!(targets.iter().all(|&(x, y)| !candidates.has(x, y, 3)));
+            assert!(fish.iter().all(|&(x, y)| candidates.has(x, y, 3)));
+            assert_eq!(swordfish(&mut candidates), 0);
+        }
+
+        #[test]
+        fn test_rows() {
+            let mut candidates: Candidates = Candidates(vec![vec![Some(1 << 1); 9]; 9]);
+            for (y, positions) in [(0, [0, 4]), (3, [4, 8]), (6, [0, 8])] {
+                candidates.0[y] = vec![Some(0); 9];
+                for x in positions {
+                    candidates.0[y][x] = Some(1 << 1);
+                }
+            }
+
+            assert_eq!(swordfish(&mut candidates), 18);
+            for y in (0..9).filter(|y| ![0, 3, 6].contains(y)) {
+                assert!([0, 4, 8].iter().all(|&x| !candidates.has(x, y, 1)));
+                assert!([1, 2, 3, 5, 6, 7].iter().all(|&x| candidates.has(x, y, 1)));
+            }
+        }
+    }
+    mod x_wing {
+        use super::*;
+
+        // Only 1 is a candidate, and it is confined to two positions on two lines
+        fn candidates(lines: [usize; 2], positions: [usize; 2], transpose: bool) -> Candidates {
+            let mut candidates = Candidates(vec![vec![Some(1 << 1); 9]; 9]);
+            for line in lines {
+                for pos in (0..9).filter(|pos| !positions.contains(pos)) {
+                    let (x, y) = if transpose { (line, pos) } else { (pos, line) };
+                    candidates.0[y][x] = Some(0);
+                }
+            }
+            candidates
+        }
+
+        #[test]
+        fn test_rows() {
+            let mut candidates = candidates([1, 5], [2, 6], false);
+            assert_eq!(naked_pairs(&mut candidates.clone()), 0);
+            assert_eq!(hidden_pairs(&mut candidates.clone()), 0);
+            assert_eq!(pointing_pairs(&mut candidates.clone()), 0);
+            assert_eq!(box_line_reduction(&mut candidates.clone()), 0);
+            assert_eq!(swordfish(&mut candidates.clone()), 0);
+
+            assert_eq!(x_wing(&mut candidates), 14);
+            for y in (0..9).filter(|y| ![1, 5].contains(y)) {
+                assert!(!candidates.has(2, y, 1) && !candidates.has(6, y, 1));
+                assert!(candidates.has(0, y, 1));
+            }
+            assert!(candidates.has(2, 1, 1) && candidates.has(6, 5, 1));
+            assert_eq!(x_wing(&mut candidates), 0);
+        }
+
+        #[test]
+        fn test_columns() {
+            let mut candidates = candidates([0, 7], [3, 4], true);
+
+            assert_eq!(x_wing(&mut candidates), 14);
+            for x in (0..9).filter(|x| ![0, 7].contains(x)) {
+                assert!(!candidates.has(x, 3, 1) && !candidates.has(x, 4, 1));
+            }
+        }
+    }
+
+    mod backtracking {
+        use super::*;
+
+        fn stalled() -> Puzzle {
+            include_str!("puzzles/hard/1/input.txt").parse().unwrap()
+        }
+
+        #[test]
+        fn test_rules_stall() {
+            let mut solver = Solver::from(stalled());
+            solver.apply_rules();
+
+            assert!(solver.puzzle.is_valid());
+            assert!(!solver.puzzle.is_complete());
+        }
+
+        #[test]
+        fn test_solve() {
+            let puzzle = stalled();
+            let mut solver = Solver::from(puzzle.clone());
+
+            assert!(solver.solve().is_ok());
+            assert!(solver.puzzle.is_valid());
+            assert!(solver.puzzle.is_complete());
+            assert_eq!(
+                solver.puzzle,
+                include_str!("puzzles/hard/1/output.txt").parse().unwrap()
+            );
+            assert!(puzzle
+                .get_cells()
+                .into_iter()
+                .filter(|cell| cell.value() != CellValue::EMPTY)
+                .all(|cell| solver.puzzle.get_cell(cell.idx.clone()).value() == cell.value()));
+        }
+
+        #[test]
+        fn test_unsolvable() {
+            // (8, 0) has no candidates: its row holds 1-8 and its column a 9
+            let puzzle: Puzzle = format!("12345678-\n--------9\n{}", "---------\n".repeat(7))
+                .parse()
+                .unwrap();
+            assert!(puzzle.is_valid());
+
+            assert!(matches!(
+                Solver::from(puzzle).solve(),
+                Err(PuzzleError::Unsolvable)
+            ));
+        }
+
+        #[test]
+        fn test_invalid() {
+            let mut puzzle = stalled();
+            puzzle.0[0][1] = CellValue::EIGHT;
+
+            assert!(matches!(
+                Solver::from(puzzle).solve(),
+                Err(PuzzleError::Unsolvable)
+            ));
+        }
+    }
+    mod candidates {
+        use super::*;
+
+        #[test]
+        fn test_candidates() {
+            let puzzle: Puzzle = include_str!("puzzles/easy/1/input.txt").parse().unwrap();
+            let candidates = puzzle.candidates();
+
+            assert!(candidates.get(0, 0).is_empty());
+            assert_eq!(candidates.get(1, 0), vec![CellValue::SIX]);
+            for cell in puzzle.get_empty_cells() {
+                let mut possible = cell.get_possible_values();
+                possible.sort_by_key(|&value| value.digit());
+                assert_eq!(candidates.get(cell.idx.x, cell.idx.y), possible);
+            }
+            assert!(candidates.dead_ends().is_empty());
+        }
+
+        #[test]
+        fn test_filled_cells() {
+            let puzzle: Puzzle = include_str!("puzzles/easy/1/output.txt").parse().unwrap();
+            let candidates = puzzle.candidates();
+
+            assert!((0..9).all(|y| (0..9).all(|x| candidates.get(x, y).is_empty())));
+            assert!(candidates.dead_ends().is_empty());
+        }
+
+        #[test]
+        fn test_dead_ends() {
+            let puzzle: Puzzle = format!("12345678-\n--------9\n{}", "---------\n".repeat(7))
+                .parse()
+                .unwrap();
+            let candidates = puzzle.candidates();
+
+            assert_eq!(candidates.dead_ends(), vec![(8, 0)]);
+            assert!(candidates.get(8, 0).is_empty());
+            assert_eq!(candidates.get(8, 2).len(), 6);
+        }
+    }
+    mod naked_pairs {
+        use super::*;
+
+        #[test]
+        fn test_fixture() {
+            let puzzle: Puzzle = include_str!("puzzles/naked_pairs/1/input.txt")
+                .parse()
+                .unwrap();
+            assert!(last_possible(&puzzle).is_empty());
+            assert!(last_remaining(&puzzle).is_empty());
+            assert_eq!(swordfish(&mut puzzle.candidates()), 0);
+
+            let mut candidates = puzzle.candidates();
+            assert_eq!(
+                candidates.get(3, 1),
+                vec![CellValue::FIVE, CellValue::SEVEN]
+            );
+            assert_eq!(
+                candidates.get(6, 1),
+                vec![CellValue::FIVE, CellValue::SEVEN]
+            );
+            assert_eq!(candidates.get(7, 1), vec![CellValue::ONE, CellValue::SEVEN]);
+
+            assert_eq!(naked_pairs(&mut candidates), 1);
+            assert_eq!(candidates.get(7, 1), vec![CellValue::ONE]);
+            assert!(candidates
+                .singles("naked pairs")
+                .iter()
+                .any(|assignment| assignment.idx.x == 7
+                    && assignment.idx.y == 1
+                    && assignment.value == CellValue::ONE));
+            assert_eq!(naked_pairs(&mut candidates), 0);
+        }
+    }
+    mod subsets {
+        use super::*;
+
+        fn candidates() -> Candidates {
+            Candidates(vec![vec![Some(0b11_1111_1110); 9]; 9])
+        }
+
+        fn set(candidates: &mut Candidates, x: usize, y: usize, digits: &[usize]) {
+            candidates.0[y][x] = Some(digits.iter().fold(0, |mask, digit| mask | 1 << digit));
+        }
+
+        #[test]
+        fn test_combinations() {
+            assert_eq!(combinations(3, 2), vec![vec![0, 1], vec![0, 2], vec![1, 2]]);
+            assert_eq!(combinations(9, 3).len(), 84);
+            assert!(combinations(2, 3).is_empty());
+        }
+
+        #[test]
+        fn test_naked_triples() {
+            let mut candidates = candidates();
+            set(&mut candidates, 0, 0, &[1, 2]);
+            set(&mut candidates, 4, 0, &[2, 3]);
+            set(&mut candidates, 8, 0, &[1, 3]);
+            set(&mut candidates, 3, 0, &[1, 4]);
+            assert_eq!(naked_pairs(&mut candidates), 0);
+
+            assert!(naked_triples(&mut candidates) > 0);
+            assert_eq!(candidates.get(3, 0), vec![CellValue::FOUR]);
+            assert!((1..8)
+                .filter(|&x| x != 4)
+                .all(|x| (1..=3).all(|digit| !candidates.has(x, 0, digit))));
+            assert_eq!(candidates.get(0, 0), vec![CellValue::ONE, CellValue::TWO]);
+            assert!(candidates
+                .singles("naked triples")
+                .iter()
+                .any(|step| step.idx == CellIndex { x: 3, y: 0 } && step.value == CellValue::FOUR));
+        }
+
+        #[test]
+        fn test_hidden_pairs() {
+            let mut candidates = candidates();
+            for x in 2..9 {
+                candidates.remove(x, 0, 1);
+                candidates.remove(x, 0, 2);
+            }
+            // 5 only fits the first cell of the pair and one other cell in the row
+            for x in 3..9 {
+                candidates.remove(x, 0, 5);
+            }
+            candidates.remove(1, 0, 5);
+
+            assert_eq!(hidden_pairs(&mut candidates), 13);
+            assert_eq!(candidates.get(0, 0), vec![CellValue::ONE, CellValue::TWO]);
+            assert_eq!(candidates.get(1, 0), vec![CellValue::ONE, CellValue::TWO]);
+            assert_eq!(
+                (0..9)
+                    .filter(|&x| candidates.has(x, 0, 5))
+                    .collect::<Vec<_>>(),
+                vec![2]
+            );
+            assert_eq!(hidden_pairs(&mut candidates), 0);
+        }
+
+        #[test]
+        fn test_hidden_triples() {
+            let mut candidates = candidates();
+            for x in [1, 2, 3, 5, 6, 7] {
+                for digit in 1..=3 {
+                    candidates.remove(x, 0, digit);
+                }
+            }
+            candidates.remove(0, 0, 3);
+            candidates.remove(4, 0, 1);
+            candidates.remove(8, 0, 2);
+            // 4 only fits one cell of the triple and one other cell in the row
+            for x in 2..9 {
+                candidates.remove(x, 0, 4);
+            }
+            assert_eq!(hidden_pairs(&mut candidates), 0);
+
+            assert_eq!(hidden_triples(&mut candidates), 16);
+            assert_eq!(candidates.get(0, 0), vec![CellValue::ONE, CellValue::TWO]);
+            assert_eq!(candidates.get(4, 0), vec![CellValue::TWO, CellValue::THREE]);
+            assert_eq!(candidates.get(8, 0), vec![CellValue::ONE, CellValue::THREE]);
+            assert_eq!(
+                (0..9)
+                    .filter(|&x| candidates.has(x, 0, 4))
+                    .collect::<Vec<_>>(),
+                vec![1]
+            );
+        }
+    }
+
+    mod box_line {
+        use super::*;
+
+        fn candidates() -> Candidates {
+            Candidates(vec![vec![Some(0b11_1111_1110); 9]; 9])
+        }
+
+        #[test]
+        fn test_pointing_pairs() {
+            let mut candidates = candidates();
+            for idx in SubgridIndex::<3>::new(0)
+                .cells()
+                .into_iter()
+                .filter(|idx| idx.y > 0)
+            {
+                candidates.remove(idx.x, idx.y, 1);
+            }
+
+            assert_eq!(pointing_pairs(&mut candidates), 6);
+            assert!((3..9).all(|x| !candidates.has(x, 0, 1)));
+            assert!((0..3).all(|x| candidates.has(x, 0, 1)));
+            assert!((1..9).all(|y| (3..9).all(|x| candidates.has(x, y, 1))));
+            assert_eq!(pointing_pairs(&mut candidates), 0);
+        }
+
+        #[test]
+        fn test_pointing_column() {
+            let mut candidates = candidates();
+            for idx in SubgridIndex::<3>::new(4)
+                .cells()
+                .into_iter()
+                .filter(|idx| idx.x != 5)
+            {
+                candidates.remove(idx.x, idx.y, 9);
+            }
+
+            assert_eq!(pointing_pairs(&mut candidates), 6);
+            assert!((0..9)
+                .filter(|y| !(3..6).contains(y))
+                .all(|y| !candidates.has(5, y, 9)));
+        }
+
+        #[test]
+        fn test_box_line_reduction() {
+            let mut candidates = candidates();
+            for x in 3..9 {
+                candidates.remove(x, 0, 1);
+            }
+
+            assert_eq!(box_line_reduction(&mut candidates), 6);
+            assert!((1..3).all(|y| (0..3).all(|x| !candidates.has(x, y, 1))));
+            assert!((0..3).all(|x| candidates.has(x, 0, 1)));
+            assert!((3..9).all(|y| (0..3).all(|x| candidates.has(x, y, 1))));
+            assert_eq!(box_line_reduction(&mut candidates), 0);
+        }
+    }
+    mod solution_count {
+        use super::*;
+
+        #[test]
+        fn test_unique() {
+            let puzzle: Puzzle = include_str!("puzzles/hard/1/input.txt").parse().unwrap();
+            let before = puzzle.clone();
+
+            assert_eq!(puzzle.solution_count(), 1);
+            assert_eq!(puzzle, before);
+        }
+
+        #[test]
+        fn test_empty_grid() {
+            let puzzle: Puzzle = "---------\n".repeat(9).parse().unwrap();
+
+            assert_eq!(puzzle.solution_count(), 2);
+        }
+
+        #[test]
+        fn test_contradiction() {
+            let dead_end: Puzzle = format!("12345678-\n--------9\n{}", "---------\n".repeat(7))
+                .parse()
+                .unwrap();
+            let mut duplicate = dead_end.clone();
+            duplicate.0[0][8] = CellValue::ONE;
+
+            assert_eq!(dead_end.solution_count(), 0);
+            assert_eq!(duplicate.solution_count(), 0);
+        }
+
+        #[test]
+        fn test_complete_grid() {
+            let puzzle: Puzzle = include_str!("puzzles/easy/1/output.txt").parse().unwrap();
+
+            assert_eq!(puzzle.solution_count(), 1);
+        }
+    }
+    mod display {
+        use super::*;
+
+        fn puzzle() -> Puzzle {
+            include_str!("puzzles/easy/1/input.txt").parse().unwrap()
+        }
+
+        #[test]
+        fn test_display() {
+            assert_eq!(
+                puzzle().to_string(),
+                "\
+8..|5.4|7.2
+932|7.8|...
+...|1..|98.
+---+---+---
+.54|3..|...
+.2.|6.9|.5.
+...|..5|84.
+---+---+---
+.13|..6|...
+...|4.2|693
+6.9|8.7|..5"
+            );
+        }
+
+        #[test]
+        fn test_placeholder() {
+            assert!(puzzle()
+                .display_with('_')
+                .to_string()
+                .starts_with("8__|5_4|7_2\n"));
+        }
+
+        #[test]
+        fn test_plain() {
+            assert_eq!(
+                format!("{:#}", puzzle()),
+                "\
+8-- 5-4 7-2
+932 7-8 ---
+--- 1-- 98-
+
+-54 3-- ---
+-2- 6-9 -5-
+--- --5 84-
+
+-13 --6 ---
+--- 4-2 693
+6-9 8-7 --5"
+            );
+        }
+    }
+
+    mod sizes {
+        use super::*;
+
+        // A patterned 16x16 solution with every third cell removed
+        fn sixteen_by_sixteen() -> (Puzzle<4>, Puzzle<4>) {
+            let symbol =
+                |idx: usize| SYMBOLS[(4 * (idx / 16 % 4) + idx / 64 + idx % 16) % 16] as char;
+            let solution: String = (0..256).map(symbol).collect();
+            let puzzle: String = (0..256)
+                .map(|idx| if idx % 3 == 0 { '.' } else { symbol(idx) })
+                .collect();
+
+            (puzzle.parse().unwrap(), solution.parse().unwrap())
+        }
+
+        #[test]
+        fn test_four_by_four() {
+            let puzzle: Puzzle<2> = "1.3.\n.4.2\n2.4.\n.3.1".parse().unwrap();
+            let mut solver = Solver::from(puzzle);
+            solver.solve().unwrap();
+
+            assert!(solver.puzzle.is_complete());
+            assert_eq!(
+                solver.puzzle.to_string(),
+                "12|34\n34|12\n--+--\n21|43\n43|21"
+            );
+            assert_eq!(solver.difficulty(), Difficulty::Easy);
+        }
+
+        #[test]
+        fn test_sixteen_by_sixteen() {
+            let (puzzle, solution) = sixteen_by_sixteen();
+            let mut solver = Solver::from(puzzle).with_solution(solution.clone());
+            solver.solve().unwrap();
+
+            assert!(solver.puzzle.is_complete());
+            assert_eq!(solver.puzzle, solution);
+            assert!(solver.difficulty() < Difficulty::Expert);
+        }
+
+        #[test]
+        fn test_backtracking() {
+            let mut solver = Solver::from(Puzzle::<2>::empty());
+            solver.solve().unwrap();
+
+            assert!(solver.puzzle.is_complete());
+            assert_eq!(solver.difficulty(), Difficulty::Expert);
+        }
+
+        #[test]
+        fn test_symbols() {
+            let (puzzle, _) = sixteen_by_sixteen();
+
+            assert_eq!(puzzle.get(1, 0).unwrap().digit(), 2);
+            assert_eq!(puzzle.get(14, 0).unwrap().to_string(), "F");
+            assert!(matches!(
+                "5...\n....\n....\n....".parse::<Puzzle<2>>(),
+                Err(ParseError::InvalidChar { ch: '5', .. })
+            ));
+            assert!(matches!(
+                "A........".repeat(9).parse::<Puzzle>(),
+                Err(ParseError::InvalidChar { ch: 'A', .. })
+            ));
+        }
+
+        #[test]
+        fn test_bounds() {
+            let puzzle = Puzzle::<2>::empty();
+
+            assert!(CellIndex::<2>::new(3, 3).is_ok());
+            assert!(CellIndex::<2>::new(4, 0).is_err());
+            assert!(matches!(
+                puzzle.get(0, 4),
+                Err(PuzzleError::OutOfBounds { x: 0, y: 4 })
+            ));
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use rand::{rngs::StdRng, seq::SliceRandom};
+
+use crate::{digits, CellValue, Puzzle, Variant};
+
+// Digits already used in each row, column and box.
+// Diagonals are only tracked on the diagonal variant
+struct Used<const N: usize> {
+    rows: Vec<u32>,
+    cols: Vec<u32>,
+    boxes: Vec<u32>,
+    diagonals: Option<[u32; 2]>,
+}
+
+impl<const N: usize> Used<N> {
+    // None when a digit repeats in a row, column, box or tracked diagonal
+    fn new(puzzle: &Puzzle<N>, variant: Variant) -> Option<Self> {
+        let size = N * N;
+        let mut used = Used {
+            rows: vec![0; size],
+            cols: vec![0; size],
+            boxes: vec![0; size],
+            diagonals: (variant == Variant::Diagonal).then_some([0; 2]),
+        };
+        for (y, row) in puzzle.0.iter().enumerate() {
+            for (x, &value) in row.iter().enumerate() {
+                if value == CellValue::EMPTY {
+                    continue;
+                }
+                if used.free(x, y) & 1 << value.digit() == 0 {
+                    return None;
+                }
+                used.toggle(x, y, value);
+            }
+        }
+        Some(used)
+    }
+
+    fn subgrid(x: usize, y: usize) -> usize {
+        (y / N) * N + x / N
+    }
+
+    fn free(&self, x: usize, y: usize) -> u32 {
+        let all = ((1u32 << (N * N)) - 1) << 1;
+        let mut used = self.rows[y] | self.cols[x] | self.boxes[Self::subgrid(x, y)];
+        if let Some(diagonals) = self.diagonals {
+            if x == y {
+                used |= diagonals[0];
+            }
+            if x + y == N * N - 1 {
+                used |= diagonals[1];
+            }
+        }
+        !used & all
+    }
+
+    fn toggle(&mut self, x: usize, y: usize, value: CellValue) {
+        let bit = 1 << value.digit();
+        self.rows[y] ^= bit;
+        self.cols[x] ^= bit;
+        self.boxes[Self::subgrid(x, y)] ^= bit;
+        if let Some(diagonals) = &mut self.diagonals {
+            if x == y {
+                diagonals[0] ^= bit;
+            }
+            if x + y == N * N - 1 {
+                diagonals[1] ^= bit;
+            }
+        }
+    }
+}
+
+// Counts solutions up to `limit`, trying the most constrained cell first and, given an
+// rng, its digits in a random order. The puzzle is left solved once `limit` is reached,
+// and restored otherwise
+fn search<const N: usize>(
+    puzzle: &mut Puzzle<N>,
+    used: &mut Used<N>,
+    limit: usize,
+    mut rng: Option<&mut StdRng>,
+) -> usize {
+    let size = N * N;
+    let Some((x, y)) = (0..size * size)
+        .map(|idx| (idx % size, idx / size))
+        .filter(|&(x, y)| puzzle.0[y][x] == CellValue::EMPTY)
+        .min_by_key(|&(x, y)| used.free(x, y).count_ones())
+    else {
+        return 1;
+    };
+
+    let free = used.free(x, y);
+    let mut values: Vec<CellValue> = digits::<N>()
+        .filter(|value| free & 1 << value.digit() != 0)
+        .collect();
+    if let Some(rng) = rng.as_deref_mut() {
+        values.shuffle(rng);
+    }
+
+    let mut count = 0;
+    for value in values {
+        puzzle.0[y][x] = value;
+        used.toggle(x, y, value);
+        count += search(puzzle, used, limit - count, rng.as_deref_mut());
+        if count >= limit {
+            return count;
+        }
+        used.toggle(x, y, value);
+    }
+    puzzle.0[y][x] = CellValue::EMPTY;
+    count
+}
+
+// Fills every empty cell, leaving the puzzle unchanged when it has no solution
+pub(crate) fn backtrack<const N: usize>(puzzle: &mut Puzzle<N>, variant: Variant) -> bool {
+    match Used::new(puzzle, variant) {
+        Some(mut used) => search(puzzle, &mut used, 1, None) == 1,
+        None => false,
+    }
+}
+
+// Stops counting at 2, which is enough to tell a unique solution apart
+pub(crate) fn count_solutions<const N: usize>(puzzle: &Puzzle<N>, variant: Variant) -> usize {
+    match Used::new(puzzle, variant) {
+        Some(mut used) => search(&mut puzzle.clone(), &mut used, 2, None),
+        None => 0,
+    }
+}
+
+// Completes the puzzle with digits tried in a random order
+pub(crate) fn fill<const N: usize>(puzzle: &mut Puzzle<N>, rng: &mut StdRng) {
+    if let Some(mut used) = Used::new(puzzle, Variant::Standard) {
+        search(puzzle, &mut used, 1, Some(rng));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    mod search {
+        use super::*;
+
+        #[test]
+        fn test_backtrack() {
+            let mut puzzle: Puzzle<2> = "1.3.\n..1.\n.1..\n4..1".parse().unwrap();
+
+            assert!(backtrack(&mut puzzle, Variant::Standard));
+            assert_eq!(puzzle.to_line(), "1234341221434321");
+        }
+
+        #[test]
+        fn test_unsolvable() {
+            let mut puzzle: Puzzle<2> = "12..\n..3.\n....\n....".parse().unwrap();
+            let stalled = puzzle.clone();
+
+            assert!(!backtrack(&mut puzzle, Variant::Standard));
+            assert_eq!(puzzle, stalled);
+            assert_eq!(count_solutions(&puzzle, Variant::Standard), 0);
+        }
+
+        #[test]
+        fn test_count_solutions() {
+            let unique: Puzzle<2> = "1234\n3412\n21..\n43..".parse().unwrap();
+
+            assert_eq!(count_solutions(&unique, Variant::Standard), 1);
+            assert_eq!(count_solutions(&Puzzle::<2>::empty(), Variant::Standard), 2);
+        }
+
+        #[test]
+        fn test_fill() {
+            let mut puzzle = Puzzle::<4>::empty();
+            fill(&mut puzzle, &mut StdRng::seed_from_u64(1));
+
+            assert!(puzzle.is_complete());
+        }
+    }
+}
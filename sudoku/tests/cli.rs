@@ -0,0 +1,102 @@
+use std::{
+    io::Write,
+    process::{Command, Output, Stdio},
+};
+
+use sudoku::Puzzle;
+
+fn sudoku(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_sudoku"))
+        .args(["--input", "src/puzzles/easy/1/input.txt"])
+        .args(args)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn test_quiet_prints_only_solution() {
+    let output = sudoku(&["--quiet"]);
+    let expected: Puzzle = include_str!("../src/puzzles/easy/1/output.txt")
+        .parse()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        format!("{}\n", expected)
+    );
+    assert!(output.stderr.is_empty());
+}
+
+#[test]
+fn test_verbose_logs_to_stderr() {
+    let output = sudoku(&["--verbose"]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+
+    assert!(output.status.success());
+    assert!(!stdout.contains("Assignment"));
+    assert!(stderr.contains("Number of Assignments"));
+    assert!(stderr.contains("Assignment from last possible"));
+}
+
+#[test]
+fn test_puzzle_path_argument() {
+    let output = Command::new(env!("CARGO_BIN_EXE_sudoku"))
+        .args(["src/puzzles/easy/1/input.txt", "--quiet"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .unwrap();
+    let expected: Puzzle = include_str!("../src/puzzles/easy/1/output.txt")
+        .parse()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        format!("{}\n", expected)
+    );
+}
+
+fn sudoku_stdin(input: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_sudoku"))
+        .arg("--quiet")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn test_reads_stdin() {
+    let output = sudoku_stdin(include_str!("../src/puzzles/easy/1/input.txt"));
+    let expected: Puzzle = include_str!("../src/puzzles/easy/1/output.txt")
+        .parse()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        format!("{}\n", expected)
+    );
+}
+
+#[test]
+fn test_parse_error_exits_non_zero() {
+    let output = sudoku_stdin("123");
+
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .starts_with("Could not parse puzzle"));
+}
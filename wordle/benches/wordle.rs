@@ -0,0 +1,153 @@
+#![allow(clippy::redundant_closure)]
+
+use std::collections::HashMap;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use wordle::{
+    Bitmask, LargestUniqueValuesRanker, LowestMaxBucketRanker, PatternMatrix, Ranker, WordClues,
+    WordProcessor, WordSuggestor,
+};
+
+fn word_bank() -> Vec<WordProcessor<'static>> {
+    include_str!("../src/word_bank.txt")
+        .lines()
+        .map(|s| WordProcessor::new(s))
+        .collect()
+}
+
+fn bench_unique_ranker(c: &mut Criterion) {
+    let words = word_bank();
+    let possible_solutions: Vec<&WordProcessor> = words.iter().collect();
+    let ranker = LargestUniqueValuesRanker::new();
+    c.bench_function("unique_ranker", |b| {
+        b.iter(|| ranker.rank(black_box(&possible_solutions), black_box(&words[0])))
+    });
+}
+
+fn bench_lowest_ranker(c: &mut Criterion) {
+    let words = word_bank();
+    let possible_solutions: Vec<&WordProcessor> = words.iter().collect();
+    let ranker = LowestMaxBucketRanker::new();
+    c.bench_function("lowest_ranker", |b| {
+        b.iter(|| ranker.rank(black_box(&possible_solutions), black_box(&words[0])))
+    });
+}
+
+// Every guess ranked against the first thousand words, computing clues on the fly or
+// looking them up in a matrix built beforehand
+fn bench_matrix_ranker(c: &mut Criterion) {
+    let words = word_bank();
+    let guesses: Vec<&WordProcessor> = words.iter().collect();
+    let possible_solutions = &guesses[..1000];
+    let columns: Vec<usize> = (0..possible_solutions.len()).collect();
+    let ranker = LowestMaxBucketRanker::new();
+    let matrix = PatternMatrix::new(&guesses, possible_solutions);
+
+    let mut group = c.benchmark_group("matrix_ranker");
+    group.bench_function("on_the_fly", |b| {
+        b.iter(|| {
+            guesses
+                .iter()
+                .map(|guess| ranker.rank(black_box(possible_solutions), guess))
+                .max()
+        })
+    });
+    group.bench_function("matrix", |b| {
+        b.iter(|| {
+            (0..guesses.len())
+                .map(|guess| matrix.rank(&ranker, guess, black_box(&columns)))
+                .max()
+        })
+    });
+    group.finish();
+}
+
+fn bench_clue_creation(c: &mut Criterion) {
+    let first = WordProcessor::new("vixon");
+    let second = WordProcessor::new("apple");
+
+    c.bench_function("clue_creation", |b| {
+        b.iter(|| WordClues::from_solution(black_box(&first), black_box(&second)))
+    });
+}
+
+fn bench_word_processor(c: &mut Criterion) {
+    let word = "vixon";
+
+    c.bench_function("word_processor", |b| {
+        b.iter(|| WordProcessor::new(black_box(word)))
+    });
+}
+
+fn bench_word_processor_hash_insertion(c: &mut Criterion) {
+    let word = "vixon";
+    c.bench_function("word_processor_hash_insertion", |b| {
+        b.iter(|| {
+            let mut map: HashMap<char, Bitmask> = HashMap::with_capacity(26);
+            word.chars().enumerate().fold(&mut map, |acc, (idx, c)| {
+                acc.entry(c).or_default().add(idx);
+                acc
+            });
+        })
+    });
+}
+
+fn hashing_baseline(c: &mut Criterion) {
+    let mut map: HashMap<char, Bitmask> = HashMap::with_capacity(0);
+    c.bench_function("hashing_baseline", |b| {
+        b.iter(|| {
+            map.entry('c').or_default().add(1);
+        })
+    });
+}
+
+fn bench_filter_word_bank(c: &mut Criterion) {
+    let word_bank: Vec<WordProcessor> = vec!["abaci", "ocuby", "thowt"]
+        .into_iter()
+        .map(|s| WordProcessor::new(s))
+        .collect();
+    let word_clues: Vec<WordClues> = vec![];
+
+    c.bench_function("filter_word_bank", |b| {
+        b.iter(|| {
+            word_bank
+                .iter()
+                .filter(|solution| {
+                    word_clues.iter().all(|clue| {
+                        WordClues::from_solution(clue.word(), solution).get_colors()
+                            == clue.get_colors()
+                    })
+                })
+                .collect::<Vec<&WordProcessor>>()
+        })
+    });
+}
+
+fn bench_word_suggestor(c: &mut Criterion) {
+    let word_bank: Vec<WordProcessor> = vec!["abaci", "ocuby", "thowt"]
+        .into_iter()
+        .map(|s| WordProcessor::new(s))
+        .collect();
+
+    let word_suggestor = WordSuggestor::new(word_bank);
+    let ranker = LowestMaxBucketRanker::new();
+    c.bench_function("word_suggestor", |b| {
+        b.iter(|| word_suggestor.suggest_word(&ranker, false))
+    });
+}
+
+criterion_group! {
+    name = rankers;
+    config = Criterion::default().sample_size(20);
+    targets = bench_unique_ranker, bench_lowest_ranker, bench_matrix_ranker
+}
+criterion_group!(
+    benches,
+    bench_clue_creation,
+    bench_word_processor,
+    bench_word_processor_hash_insertion,
+    hashing_baseline,
+    bench_filter_word_bank,
+    bench_word_suggestor
+);
+criterion_main!(rankers, benches);
@@ -1,327 +1,145 @@
-#![feature(test)]
-#![feature(iter_array_chunks)]
-
 extern crate entity;
-extern crate rayon;
-extern crate test;
-
-use std::collections::HashMap;
-use std::collections::HashSet;
-use std::str::FromStr;
-
-use indicatif::ProgressBar;
-use rayon::prelude::*;
-
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub enum Color {
-    GRAY,   // Don't know if the word contains this letter, initial state
-    BLACK,  // The word does not contain this letter
-    YELLOW, // The word does contain this letter
-    GREEN,  // The position of this letter is known in the word
-}
 
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct Clues([Color; 5]);
-
-impl FromStr for Clues {
-    type Err = String;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Clues(
-            s.chars()
-                .array_chunks::<5>()
-                .take(1)
-                .map(|window: [char; 5]| {
-                    window.map(|c| match c {
-                        'b' => Color::BLACK,
-                        'y' => Color::YELLOW,
-                        'g' => Color::GREEN,
-                        _ => panic!("Unsupported color {}", c),
-                    })
-                })
-                .next()
-                .unwrap(),
-        ))
-    }
-}
-
-#[derive(Debug)]
-pub struct Bitmask(usize);
-
-impl Bitmask {
-    pub fn new() -> Self {
-        Bitmask(0)
-    }
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
 
-    pub fn add(&mut self, value: usize) {
-        self.0 |= 1 << value;
-    }
-
-    pub fn has(&self, value: usize) -> bool {
-        (self.0 & 1 << value) > 0
-    }
-
-    pub fn remove(&mut self, value: usize) {
-        if self.has(value) {
-            self.0 ^= 1 << value;
-        }
-    }
+use cli::{CommonArgs, Parser};
+use dictionary::WordBank;
+use errors::PuzzleError;
+use tracing::info;
+use wordle::{Clues, LowestMaxBucketRanker, WordProcessor, WordSuggestor};
 
-    pub fn intersection(&self, other: &Bitmask) -> Bitmask {
-        Bitmask(self.0 & other.0)
-    }
+#[derive(Parser, Debug, PartialEq, Eq)]
+struct Args {
+    #[command(flatten)]
+    common: CommonArgs,
 
-    pub fn symmetric_difference(&self, other: &Bitmask) -> Bitmask {
-        Bitmask((self.0 & other.0) ^ (self.0 | other.0))
-    }
+    /// Number of letters in the word being guessed
+    #[arg(long, default_value_t = 5)]
+    length: usize,
 
-    pub fn values(&self) -> impl Iterator<Item = usize> {
-        let value = self.0;
-        (0..64).filter(move |idx| value & (1 << idx) > 0)
-    }
-}
+    /// Word list to guess from instead of the bundled word bank
+    #[arg(long)]
+    words: Option<PathBuf>,
 
-impl Default for Bitmask {
-    fn default() -> Self {
-        Bitmask::new()
-    }
-}
+    /// Only suggest words that fit every clue so far
+    #[arg(long)]
+    hard: bool,
 
-pub struct WordProcessor<'a> {
-    map: HashMap<char, Bitmask>,
-    word: &'a str,
+    /// Play a live game, typing the colors for each suggested guess
+    #[arg(long)]
+    interactive: bool,
 }
 
-impl<'a> WordProcessor<'a> {
-    fn new(word: &'a str) -> Self {
-        let mut map: HashMap<char, Bitmask> = HashMap::with_capacity(26);
-        word.chars().enumerate().for_each(|(idx, c)| {
-            map.entry(c).or_default().add(idx);
-        });
-
-        WordProcessor { map, word }
+// Loads the words of the requested length, failing rather than suggesting from an empty bank
+fn load_word_bank(words: Option<&PathBuf>, length: usize) -> Result<WordBank, String> {
+    let word_bank = match words {
+        Some(path) => dictionary::load_from_file(path)
+            .map_err(|err| format!("Could not read word file {}: {}", path.display(), err))?,
+        None => WordBank::parse(include_str!("../word_bank.txt")),
     }
-
-    fn get(&self, c: char) -> Option<&Bitmask> {
-        self.map.get(&c)
-    }
-
-    fn entries(&self) -> impl Iterator<Item = (&char, &Bitmask)> {
-        self.map.iter()
+    .with_length(length);
+    if word_bank.is_empty() {
+        return Err(format!("No {}-letter words in the word bank", length));
     }
+    Ok(word_bank)
 }
 
-pub struct WordClues<'a> {
-    clues: Clues,
-    word: &'a WordProcessor<'a>,
-}
-
-impl<'a> WordClues<'a> {
-    fn from_clues(word: &'a WordProcessor, clues: Clues) -> Self {
-        WordClues { word, clues }
-    }
-
-    fn from_solution(word: &'a WordProcessor, solution: &WordProcessor) -> Self {
-        let mut map: HashMap<usize, Color> = HashMap::with_capacity(5);
-
-        word.entries().for_each(|(&key, word_set)| {
-            if let Some(solution_set) = solution.get(key) {
-                word_set
-                    .intersection(solution_set)
-                    .values()
-                    .for_each(|value| {
-                        map.insert(value, Color::GREEN);
-                    });
-
-                let max_yellows = solution_set
-                    .values()
-                    .filter(|&value| !word_set.has(value))
-                    .count();
-                let yellows: Vec<usize> = word_set
-                    .values()
-                    .filter(|value| !map.contains_key(value))
-                    .take(max_yellows)
-                    .collect();
-                yellows.iter().for_each(|&value| {
-                    map.insert(value, Color::YELLOW);
-                })
-            }
-        });
-
-        let mut colors: [Color; 5] = [Color::BLACK; 5];
-
-        map.iter().for_each(|(&key, &value)| {
-            colors[key] = value;
-        });
-        let clues = Clues(colors);
-
-        WordClues { clues, word }
-    }
-
-    fn get_colors(&self) -> &Clues {
-        &self.clues
-    }
-}
-
-impl<'a> From<WordClues<'a>> for Clues {
-    fn from(value: WordClues<'a>) -> Self {
-        value.clues
-    }
-}
-
-pub struct WordSuggestor<'a> {
-    word_bank: Vec<WordProcessor<'a>>,
-    word_clues: Vec<&'a WordClues<'a>>,
-}
-
-impl<'a> WordSuggestor<'a> {
-    pub fn new(word_bank: Vec<WordProcessor<'a>>) -> Self {
-        WordSuggestor {
-            word_bank,
-            word_clues: vec![],
-        }
-    }
-    pub fn suggest_word<T>(&self, ranker: &T, show_progress: bool) -> String
-    where
-        T: Ranker,
-    {
-        if self.word_clues.len() == 0 {
-            return "serai".to_owned();
+// Prompts for the colors of a guess until they parse. None once the input runs out
+fn read_clues(
+    guess: &str,
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+) -> io::Result<Option<Clues>> {
+    loop {
+        write!(output, "Colors for {}: ", guess)?;
+        output.flush()?;
+
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(None);
         }
-        println!("Calculating possible solutions");
-        let possible_solutions: Vec<&WordProcessor> = self
-            .word_bank
-            .iter()
-            .filter(|solution| {
-                self.word_clues.iter().all(|clue| {
-                    WordClues::from_solution(clue.word, solution).get_colors() == clue.get_colors()
-                })
-            })
-            .collect();
-        println!("Number of possible solutions: {}", possible_solutions.len());
-
-        if possible_solutions.is_empty() {
-            return "".to_owned();
+        match Clues::for_word(guess, &line) {
+            Ok(clues) => return Ok(Some(clues)),
+            Err(err) => writeln!(output, "{}", err)?,
         }
-
-        if possible_solutions.len() == 1 {
-            return possible_solutions.first().unwrap().word.to_owned();
-        }
-
-        println!("Calculating suggestion");
-        let progress_bar = if show_progress {
-            ProgressBar::new(self.word_bank.len() as u64)
-        } else {
-            ProgressBar::hidden()
-        };
-        let suggestion = self
-            .word_bank
-            .par_iter()
-            .max_by_key(|&word| {
-                progress_bar.inc(1);
-                ranker.rank(&possible_solutions, word)
-            })
-            .unwrap();
-
-        suggestion.word.to_owned()
-    }
-
-    pub fn add_clue(&mut self, word_clue: &'a WordClues<'a>) {
-        self.word_clues.push(word_clue);
-    }
-}
-
-pub trait Ranker: Sync + Send {
-    fn rank(&self, possible_solutions: &[&WordProcessor], word: &WordProcessor) -> usize;
-}
-
-pub struct LowestMaxBucketRanker;
-
-impl LowestMaxBucketRanker {
-    pub fn new() -> Self {
-        LowestMaxBucketRanker {}
     }
 }
 
-impl Default for LowestMaxBucketRanker {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-impl Ranker for LowestMaxBucketRanker {
-    fn rank(&self, possible_solutions: &[&WordProcessor], word: &WordProcessor) -> usize {
-        let mut map = HashMap::<Clues, usize>::new();
-        possible_solutions.iter().for_each(|solution| {
-            let word_clues = WordClues::from_solution(word, solution);
-            *map.entry(word_clues.into()).or_default() += 1;
-        });
-        possible_solutions.len() - map.values().max().unwrap()
-    }
-}
-
-pub struct LargestUniqueValuesRanker;
-
-impl LargestUniqueValuesRanker {
-    pub fn new() -> Self {
-        LargestUniqueValuesRanker {}
-    }
-}
+// Suggests a guess, records its colors and repeats until solved. Returns the solution if found
+fn play<'a>(
+    word_suggestor: &mut WordSuggestor<'a>,
+    bank: &[&'a str],
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+) -> io::Result<Option<&'a str>> {
+    let ranker = LowestMaxBucketRanker::new();
+    loop {
+        let suggestion = word_suggestor.suggest_word(&ranker, false);
+        let Some(&guess) = bank.iter().find(|&&word| word == suggestion) else {
+            writeln!(output, "No words left")?;
+            return Ok(None);
+        };
+        writeln!(output, "Guess: {}", guess)?;
 
-impl Default for LargestUniqueValuesRanker {
-    fn default() -> Self {
-        Self::new()
+        let Some(clues) = read_clues(guess, input, output)? else {
+            return Ok(None);
+        };
+        if clues.is_solved() {
+            return Ok(Some(guess));
+        }
+        word_suggestor
+            .add_guess(guess, clues)
+            .map_err(io::Error::other)?;
     }
 }
 
-impl Ranker for LargestUniqueValuesRanker {
-    fn rank(&self, possible_solutions: &[&WordProcessor], word: &WordProcessor) -> usize {
-        possible_solutions
-            .iter()
-            .map(|solution| WordClues::from_solution(word, solution).into())
-            .collect::<HashSet<Clues>>()
-            .len()
-    }
-}
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let words: Vec<WordProcessor> = include_str!("../word_bank.txt")
-        .lines()
-        .map(WordProcessor::new)
-        .collect();
-
-    println!("created word bank");
-    let mut word_suggestor = WordSuggestor::new(words);
-    let processors: Vec<WordProcessor> = include_str!("../clues.txt")
-        .lines()
-        .map(|s| {
-            let mut split = s.split(" ");
-            let word = split.next().unwrap();
-            WordProcessor::new(word)
-        })
-        .collect();
-    let clues: Vec<Clues> = include_str!("../clues.txt")
-        .lines()
-        .map(|s| {
-            let mut split = s.split(" ");
-            split.next();
-            split.next().unwrap().parse().unwrap()
-        })
-        .collect();
-
-    let word_clues: Vec<WordClues> = processors
-        .iter()
-        .zip(clues.into_iter())
-        .map(|(processor, clues)| WordClues::from_clues(processor, clues))
-        .collect();
-
-    for word_clue in &word_clues {
-        word_suggestor.add_clue(word_clue);
+    let args = Args::parse();
+    args.common.init_tracing();
+    let clues_input = match args.common.read_input()? {
+        Some(input) => input,
+        None if args.interactive => String::new(),
+        None => include_str!("../clues.txt").to_owned(),
+    };
+
+    let word_bank = load_word_bank(args.words.as_ref(), args.length)?;
+    let words: Vec<WordProcessor> = word_bank.iter().map(WordProcessor::new).collect();
+
+    info!("Created word bank");
+    let mut word_suggestor = WordSuggestor::new(words).with_hard_mode(args.hard);
+    for line in clues_input.lines().filter(|line| !line.trim().is_empty()) {
+        let (word, clues) = line
+            .trim()
+            .split_once(' ')
+            .ok_or_else(|| PuzzleError::parse(format!("Expected a word and clues: {}", line)))?;
+        word_suggestor.add_guess(word, Clues::for_word(word, clues)?)?;
+    }
+
+    if args.interactive {
+        let bank: Vec<&str> = word_bank.iter().collect();
+        let solution = play(
+            &mut word_suggestor,
+            &bank,
+            &mut io::stdin().lock(),
+            &mut io::stdout(),
+        )?;
+        if let Some(solution) = solution {
+            println!("Solved: {}", solution);
+        }
+        return Ok(());
     }
 
-    println!(
-        "Suggestion: {}",
-        word_suggestor.suggest_word(&LowestMaxBucketRanker::new(), true)
-    );
+    let suggestion =
+        word_suggestor.suggest_word(&LowestMaxBucketRanker::new(), args.common.verbose);
+    if args.common.json {
+        println!("{}", serde_json::json!({ "suggestion": suggestion }));
+    } else if args.common.quiet {
+        println!("{}", suggestion);
+    } else {
+        println!("Suggestion: {}", suggestion);
+    }
 
     Ok(())
 }
@@ -329,356 +147,110 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use test::Bencher;
 
-    mod bitmask {
+    mod args {
         use super::*;
 
         #[test]
-        fn test_init() {
-            let mask = Bitmask::new();
-            assert_eq!(mask.0, 0);
+        fn test_args() {
+            let args = Args::try_parse_from(["solve", "--input", "clues.txt", "--quiet"]).unwrap();
+
+            assert_eq!(args.common.input, Some("clues.txt".into()));
+            assert!(args.common.quiet);
+            assert!(!args.common.json);
+            assert_eq!(args.length, 5);
+            assert!(!args.hard);
+            assert!(!args.interactive);
+            assert!(Args::try_parse_from(["solve", "--bogus"]).is_err());
+
+            let args = Args::try_parse_from(["solve", "--length", "6", "--hard", "--interactive"])
+                .unwrap();
+            assert_eq!(args.length, 6);
+            assert!(args.hard);
+            assert!(args.interactive);
+            assert_eq!(args.words, None);
+
+            let args = Args::try_parse_from(["solve", "--words", "words.txt"]).unwrap();
+            assert_eq!(args.words, Some("words.txt".into()));
         }
+    }
 
-        #[test]
-        fn test_add() {
-            let mut mask = Bitmask::new();
-            mask.add(0);
-            assert_eq!(mask.0, 1);
-
-            mask.add(2);
-            assert_eq!(mask.0, 5);
-        }
+    mod word_bank {
+        use super::*;
 
         #[test]
-        fn test_remove() {
-            let mut mask = Bitmask::new();
-            mask.add(3);
-            assert_eq!(mask.0, 8);
-
-            mask.remove(3);
-            assert_eq!(mask.0, 0);
+        fn test_bundled_length() {
+            assert!(load_word_bank(None, 5).unwrap().len() > 1000);
+            assert_eq!(
+                load_word_bank(None, 6).unwrap_err(),
+                "No 6-letter words in the word bank"
+            );
         }
 
         #[test]
-        fn test_muli_add() {
-            let mut mask = Bitmask::new();
-            mask.add(3);
-            assert_eq!(mask.0, 8);
-
-            mask.add(3);
-            assert_eq!(mask.0, 8);
+        fn test_words_file() {
+            let path = PathBuf::from(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../spellingbee/src/word_bank.txt"
+            ));
+            let word_bank = load_word_bank(Some(&path), 6).unwrap();
+
+            assert!(!word_bank.is_empty());
+            assert!(word_bank.iter().all(|word| word.len() == 6));
+            assert!(load_word_bank(Some(&"/nonexistent/words.txt".into()), 6).is_err());
         }
+    }
 
-        #[test]
-        fn test_muli_remove() {
-            let mut mask = Bitmask::new();
-            mask.add(3);
-            assert_eq!(mask.0, 8);
-
-            mask.remove(3);
-            assert_eq!(mask.0, 0);
+    mod interactive {
+        use super::*;
 
-            mask.remove(3);
-            assert_eq!(mask.0, 0);
+        #[test]
+        fn test_read_clues_reprompts() {
+            let mut input = "bxbbb\nbyg\n bygbb \n".as_bytes();
+            let mut output = vec![];
+            let clues = read_clues("serai", &mut input, &mut output).unwrap();
+
+            assert_eq!(clues, Some("bygbb".parse().unwrap()));
+            let output = String::from_utf8(output).unwrap();
+            assert_eq!(output.matches("Colors for serai").count(), 3);
+            assert!(output.contains("Unsupported color x"));
+            assert!(output.contains("Expected 5 colors, found 3"));
         }
 
         #[test]
-        fn test_values() {
-            let mut mask = Bitmask::new();
-            mask.add(3);
-            mask.add(8);
-
-            let values: Vec<usize> = mask.values().collect();
-            println!("Values: {:?}", values);
-            assert!(values.contains(&3));
-            assert!(values.contains(&8));
+        fn test_read_clues_end_of_input() {
+            let mut output = vec![];
+
+            assert_eq!(
+                read_clues("serai", &mut "".as_bytes(), &mut output).unwrap(),
+                None
+            );
         }
 
         #[test]
-        fn test_intersection() {
-            let mut first = Bitmask::new();
-            first.add(1);
-            first.add(2);
-            first.add(5);
-            first.add(7);
-
-            let mut second = Bitmask::new();
-            second.add(2);
-            second.add(5);
-            second.add(6);
-            second.add(8);
-
-            let intersection = first.intersection(&second);
-
-            assert!(intersection.has(2));
-            assert!(intersection.has(5));
+        fn test_play() {
+            let bank = ["forge", "serai", "mount"];
+            let mut word_suggestor = WordSuggestor::new(bank.map(WordProcessor::new).to_vec());
+            let mut input = "bgbbb\nggggg\n".as_bytes();
+            let mut output = vec![];
+            let solution = play(&mut word_suggestor, &bank, &mut input, &mut output).unwrap();
+
+            assert_eq!(solution, Some("mount"));
+            let output = String::from_utf8(output).unwrap();
+            assert!(output.contains("Guess: forge"));
+            assert!(output.contains("Guess: mount"));
         }
 
         #[test]
-        fn test_difference() {
-            let mut first = Bitmask::new();
-            first.add(1);
-            first.add(2);
-            first.add(5);
-            first.add(7);
-
-            let mut second = Bitmask::new();
-            second.add(2);
-            second.add(5);
-            second.add(6);
-            second.add(8);
-
-            let intersection = first.symmetric_difference(&second);
-
-            assert!(intersection.has(1));
-            assert!(intersection.has(6));
-            assert!(intersection.has(7));
-            assert!(intersection.has(8));
+        fn test_play_runs_out_of_words() {
+            let bank = ["forge", "serai", "mount"];
+            let mut word_suggestor = WordSuggestor::new(bank.map(WordProcessor::new).to_vec());
+            let mut input = "ggggb\n".as_bytes();
+            let mut output = vec![];
+            let solution = play(&mut word_suggestor, &bank, &mut input, &mut output).unwrap();
+
+            assert_eq!(solution, None);
+            assert!(String::from_utf8(output).unwrap().contains("No words left"));
         }
     }
-
-    #[test]
-    fn test_colors() {
-        assert_eq!(
-            *WordClues::from_solution(&WordProcessor::new(&"saber"), &WordProcessor::new("label"))
-                .get_colors(),
-            Clues([
-                Color::BLACK,
-                Color::GREEN,
-                Color::GREEN,
-                Color::GREEN,
-                Color::BLACK
-            ])
-        );
-        assert_eq!(
-            *WordClues::from_solution(&WordProcessor::new(&"aheap"), &WordProcessor::new(&"woken"))
-                .get_colors(),
-            Clues([
-                Color::BLACK,
-                Color::BLACK,
-                Color::YELLOW,
-                Color::BLACK,
-                Color::BLACK
-            ])
-        );
-
-        assert_eq!(
-            *WordClues::from_solution(&WordProcessor::new(&"serai"), &WordProcessor::new(&"delve"))
-                .get_colors(),
-            Clues([
-                Color::BLACK,
-                Color::GREEN,
-                Color::BLACK,
-                Color::BLACK,
-                Color::BLACK
-            ])
-        );
-        assert_eq!(
-            *WordClues::from_solution(&WordProcessor::new(&"yente"), &WordProcessor::new(&"delve"))
-                .get_colors(),
-            Clues([
-                Color::BLACK,
-                Color::GREEN,
-                Color::BLACK,
-                Color::BLACK,
-                Color::GREEN
-            ])
-        );
-        assert_eq!(
-            *WordClues::from_solution(&WordProcessor::new(&"blech"), &WordProcessor::new(&"delve"))
-                .get_colors(),
-            Clues([
-                Color::BLACK,
-                Color::YELLOW,
-                Color::YELLOW,
-                Color::BLACK,
-                Color::BLACK
-            ])
-        );
-        assert_eq!(
-            *WordClues::from_solution(&WordProcessor::new(&"begem"), &WordProcessor::new(&"delve"))
-                .get_colors(),
-            Clues([
-                Color::BLACK,
-                Color::GREEN,
-                Color::BLACK,
-                Color::YELLOW,
-                Color::BLACK
-            ])
-        );
-        assert_eq!(
-            *WordClues::from_solution(&WordProcessor::new(&"welke"), &WordProcessor::new(&"delve"))
-                .get_colors(),
-            Clues([
-                Color::BLACK,
-                Color::GREEN,
-                Color::GREEN,
-                Color::BLACK,
-                Color::GREEN
-            ])
-        );
-        assert_eq!(
-            *WordClues::from_solution(&WordProcessor::new(&"mommy"), &WordProcessor::new(&"delve"))
-                .get_colors(),
-            Clues([
-                Color::BLACK,
-                Color::BLACK,
-                Color::BLACK,
-                Color::BLACK,
-                Color::BLACK
-            ])
-        );
-
-        assert_eq!(
-            *WordClues::from_solution(&WordProcessor::new(&"forge"), &WordProcessor::new(&"forge"))
-                .get_colors(),
-            Clues([Color::GREEN; 5])
-        );
-        assert_eq!(
-            *WordClues::from_solution(&WordProcessor::new(&"forte"), &WordProcessor::new(&"forge"))
-                .get_colors(),
-            Clues([
-                Color::GREEN,
-                Color::GREEN,
-                Color::GREEN,
-                Color::BLACK,
-                Color::GREEN
-            ])
-        );
-        assert_eq!(
-            *WordClues::from_solution(&WordProcessor::new(&"forze"), &WordProcessor::new(&"forge"))
-                .get_colors(),
-            Clues([
-                Color::GREEN,
-                Color::GREEN,
-                Color::GREEN,
-                Color::BLACK,
-                Color::GREEN
-            ])
-        );
-        assert_eq!(
-            *WordClues::from_solution(&WordProcessor::new(&"bafts"), &WordProcessor::new(&"forge"))
-                .get_colors(),
-            Clues([
-                Color::BLACK,
-                Color::BLACK,
-                Color::YELLOW,
-                Color::BLACK,
-                Color::BLACK
-            ])
-        );
-        assert_eq!(
-            *WordClues::from_solution(&WordProcessor::new(&"murid"), &WordProcessor::new(&"forge"))
-                .get_colors(),
-            Clues([
-                Color::BLACK,
-                Color::BLACK,
-                Color::GREEN,
-                Color::BLACK,
-                Color::BLACK
-            ])
-        );
-        assert_eq!(
-            *WordClues::from_solution(&WordProcessor::new(&"soare"), &WordProcessor::new(&"forge"))
-                .get_colors(),
-            Clues([
-                Color::BLACK,
-                Color::GREEN,
-                Color::BLACK,
-                Color::YELLOW,
-                Color::GREEN
-            ])
-        );
-    }
-
-    #[bench]
-    fn bench_unique_ranker(b: &mut Bencher) {
-        let words: Vec<WordProcessor> = include_str!("../word_bank.txt")
-            .lines()
-            .map(|s| WordProcessor::new(s))
-            .collect();
-        let possible_solutions: Vec<&WordProcessor> = words.iter().collect();
-        let ranker = LargestUniqueValuesRanker::new();
-        b.iter(|| ranker.rank(&possible_solutions, &words[0]));
-    }
-
-    #[bench]
-    fn bench_lowest_ranker(b: &mut Bencher) {
-        let words: Vec<WordProcessor> = include_str!("../word_bank.txt")
-            .lines()
-            .map(|s| WordProcessor::new(s))
-            .collect();
-        let possible_solutions: Vec<&WordProcessor> = words.iter().collect();
-        let ranker = LowestMaxBucketRanker::new();
-        b.iter(|| ranker.rank(&possible_solutions, &words[0]));
-    }
-
-    #[bench]
-    fn bench_clue_creation(b: &mut Bencher) {
-        let first = WordProcessor::new("vixon");
-        let second = WordProcessor::new("apple");
-
-        b.iter(|| WordClues::from_solution(&first, &second));
-    }
-
-    #[bench]
-    fn bench_word_processor(b: &mut Bencher) {
-        let word = "vixon";
-
-        b.iter(|| WordProcessor::new(word));
-    }
-
-    #[bench]
-    fn bench_word_processor_hash_insertion(b: &mut Bencher) {
-        let word = "vixon";
-        b.iter(|| {
-            let mut map: HashMap<char, Bitmask> = HashMap::with_capacity(26);
-            word.chars().enumerate().fold(&mut map, |acc, (idx, c)| {
-                acc.entry(c).or_default().add(idx);
-                acc
-            });
-        });
-    }
-
-    #[bench]
-    fn hashing_baseline(b: &mut Bencher) {
-        let mut map: HashMap<char, Bitmask> = HashMap::with_capacity(0);
-        b.iter(|| {
-            map.entry('c').or_default().add(1);
-        });
-    }
-
-    #[bench]
-    fn bench_filter_word_bank(b: &mut Bencher) {
-        let word_bank: Vec<WordProcessor> = vec!["abaci", "ocuby", "thowt"]
-            .into_iter()
-            .map(|s| WordProcessor::new(s))
-            .collect();
-        let word_clues: Vec<WordClues> = vec![];
-
-        b.iter(|| {
-            word_bank
-                .iter()
-                .filter(|solution| {
-                    word_clues.iter().all(|clue| {
-                        WordClues::from_solution(clue.word, solution).get_colors()
-                            == clue.get_colors()
-                    })
-                })
-                .collect::<Vec<&WordProcessor>>()
-        });
-    }
-
-    #[bench]
-    fn bench_word_suggestor(b: &mut Bencher) {
-        let word_bank: Vec<WordProcessor> = vec!["abaci", "ocuby", "thowt"]
-            .into_iter()
-            .map(|s| WordProcessor::new(s))
-            .collect();
-
-        let word_suggestor = WordSuggestor::new(word_bank);
-        let ranker = LowestMaxBucketRanker::new();
-        b.iter(|| word_suggestor.suggest_word(&ranker, false));
-    }
 }
@@ -0,0 +1,2062 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign};
+use std::str::FromStr;
+use std::sync::{Mutex, OnceLock};
+
+use errors::PuzzleError;
+use indicatif::ProgressBar;
+#[cfg(not(target_arch = "wasm32"))]
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+pub enum Color {
+    GRAY,   // Don't know if the word contains this letter, initial state
+    BLACK,  // The word does not contain this letter
+    YELLOW, // The word does contain this letter
+    GREEN,  // The position of this letter is known in the word
+}
+
+// One color per letter, so the length follows the word being played
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+pub struct Clues(Vec<Color>);
+
+// Standard Wordle clues, one color per letter of a five letter guess. Other lengths go
+// through Clues::for_word
+impl FromStr for Clues {
+    type Err = PuzzleError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Clues::parse_colors(s)?.expect_len(Clues::DEFAULT_LENGTH)
+    }
+}
+
+impl Clues {
+    pub const DEFAULT_LENGTH: usize = 5;
+
+    fn parse_colors(s: &str) -> Result<Self, PuzzleError> {
+        let colors: Vec<Color> = s
+            .trim()
+            .chars()
+            .map(|c| match c {
+                'b' => Ok(Color::BLACK),
+                'y' => Ok(Color::YELLOW),
+                'g' => Ok(Color::GREEN),
+                '?' => Ok(Color::GRAY),
+                _ => Err(PuzzleError::parse(format!("Unsupported color {}", c))),
+            })
+            .collect::<Result<_, _>>()?;
+
+        if colors.is_empty() {
+            return Err(PuzzleError::parse("Expected at least one color"));
+        }
+        Ok(Clues(colors))
+    }
+
+    fn expect_len(self, expected: usize) -> Result<Self, PuzzleError> {
+        if self.len() != expected {
+            return Err(PuzzleError::parse(format!(
+                "Expected {} colors, found {}",
+                expected,
+                self.len()
+            )));
+        }
+        Ok(self)
+    }
+}
+
+// Letters as in FromStr. With the ansi feature, {:#} prints colored squares instead
+impl Display for Clues {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for color in &self.0 {
+            #[cfg(feature = "ansi")]
+            if f.alternate() {
+                let code = match color {
+                    Color::GRAY => 90,
+                    Color::BLACK => 30,
+                    Color::YELLOW => 33,
+                    Color::GREEN => 32,
+                };
+                write!(f, "\x1b[{}m\u{25a0}\x1b[0m", code)?;
+                continue;
+            }
+            let c = match color {
+                Color::GRAY => '?',
+                Color::BLACK => 'b',
+                Color::YELLOW => 'y',
+                Color::GREEN => 'g',
+            };
+            write!(f, "{}", c)?;
+        }
+        Ok(())
+    }
+}
+
+impl Clues {
+    // Parses clues for a guess, which must have one color per letter
+    pub fn for_word(word: &str, s: &str) -> Result<Self, PuzzleError> {
+        Clues::parse_colors(s)?.expect_len(word.chars().count())
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.0.iter().all(|&color| color == Color::GREEN)
+    }
+
+    // Packs the colors two bits apiece, so distinct clues of up to 16 letters never collide
+    pub fn pattern(&self) -> u32 {
+        self.0
+            .iter()
+            .fold(0, |pattern, &color| pattern << 2 | color as u32)
+    }
+
+    // Clues of different lengths are for different words, so never match
+    pub fn matches_partial(&self, other: &Clues) -> bool {
+        self.len() == other.len()
+            && self
+                .0
+                .iter()
+                .zip(other.0.iter())
+                .all(|(&a, &b)| a == b || a == Color::GRAY || b == Color::GRAY)
+    }
+}
+
+// A set of positions 0..Bitmask::BITS, which is 64 on native targets but 32 on wasm32
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Bitmask(usize);
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct BitmaskRangeError(pub usize);
+
+impl Display for BitmaskRangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Position {} does not fit in a {} bit mask",
+            self.0,
+            Bitmask::BITS
+        )
+    }
+}
+
+impl std::error::Error for BitmaskRangeError {}
+
+impl Bitmask {
+    pub const BITS: usize = usize::BITS as usize;
+
+    pub fn new() -> Self {
+        Bitmask(0)
+    }
+
+    pub fn from_positions(positions: &[usize]) -> Self {
+        let mut mask = Bitmask::new();
+        positions.iter().for_each(|&position| mask.add(position));
+        mask
+    }
+
+    pub fn add(&mut self, value: usize) {
+        debug_assert!(value < Self::BITS, "{}", BitmaskRangeError(value));
+        self.0 |= 1 << value;
+    }
+
+    pub fn try_add(&mut self, value: usize) -> Result<(), BitmaskRangeError> {
+        if value >= Self::BITS {
+            return Err(BitmaskRangeError(value));
+        }
+        self.add(value);
+        Ok(())
+    }
+
+    // Positions past the end are never set
+    pub fn has(&self, value: usize) -> bool {
+        value < Self::BITS && (self.0 & 1 << value) > 0
+    }
+
+    pub fn remove(&mut self, value: usize) {
+        if self.has(value) {
+            self.0 ^= 1 << value;
+        }
+    }
+
+    pub fn intersection(&self, other: &Bitmask) -> Bitmask {
+        *self & *other
+    }
+
+    pub fn symmetric_difference(&self, other: &Bitmask) -> Bitmask {
+        *self ^ *other
+    }
+
+    pub fn union(&self, other: &Bitmask) -> Bitmask {
+        *self | *other
+    }
+
+    pub fn count(&self) -> u32 {
+        self.0.count_ones()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = usize> {
+        let value = self.0;
+        (0..Self::BITS).filter(move |idx| value & (1 << idx) > 0)
+    }
+}
+
+impl Default for Bitmask {
+    fn default() -> Self {
+        Bitmask::new()
+    }
+}
+
+impl BitAnd for Bitmask {
+    type Output = Bitmask;
+
+    fn bitand(self, other: Bitmask) -> Bitmask {
+        Bitmask(self.0 & other.0)
+    }
+}
+
+impl BitOr for Bitmask {
+    type Output = Bitmask;
+
+    fn bitor(self, other: Bitmask) -> Bitmask {
+        Bitmask(self.0 | other.0)
+    }
+}
+
+impl BitXor for Bitmask {
+    type Output = Bitmask;
+
+    fn bitxor(self, other: Bitmask) -> Bitmask {
+        Bitmask(self.0 ^ other.0)
+    }
+}
+
+impl BitAndAssign for Bitmask {
+    fn bitand_assign(&mut self, other: Bitmask) {
+        self.0 &= other.0;
+    }
+}
+
+impl BitOrAssign for Bitmask {
+    fn bitor_assign(&mut self, other: Bitmask) {
+        self.0 |= other.0;
+    }
+}
+
+impl BitXorAssign for Bitmask {
+    fn bitxor_assign(&mut self, other: Bitmask) {
+        self.0 ^= other.0;
+    }
+}
+
+#[derive(Clone)]
+pub struct WordProcessor<'a> {
+    map: HashMap<char, Bitmask>,
+    word: &'a str,
+}
+
+impl<'a> WordProcessor<'a> {
+    pub fn new(word: &'a str) -> Self {
+        let mut map: HashMap<char, Bitmask> = HashMap::with_capacity(26);
+        word.chars().enumerate().for_each(|(idx, c)| {
+            map.entry(c).or_default().add(idx);
+        });
+
+        WordProcessor { map, word }
+    }
+
+    pub fn word(&self) -> &'a str {
+        self.word
+    }
+
+    fn get(&self, c: char) -> Option<&Bitmask> {
+        self.map.get(&c)
+    }
+
+    fn entries(&self) -> impl Iterator<Item = (&char, &Bitmask)> {
+        self.map.iter()
+    }
+}
+
+pub struct WordClues<'a> {
+    clues: Clues,
+    word: &'a WordProcessor<'a>,
+}
+
+impl<'a> WordClues<'a> {
+    pub fn from_clues(word: &'a WordProcessor, clues: Clues) -> Self {
+        WordClues { word, clues }
+    }
+
+    pub fn from_solution(word: &'a WordProcessor, solution: &WordProcessor) -> Self {
+        let length = word.word.chars().count();
+        let mut map: HashMap<usize, Color> = HashMap::with_capacity(length);
+
+        word.entries().for_each(|(&key, word_set)| {
+            if let Some(solution_set) = solution.get(key) {
+                word_set
+                    .intersection(solution_set)
+                    .values()
+                    .for_each(|value| {
+                        map.insert(value, Color::GREEN);
+                    });
+
+                let max_yellows = solution_set
+                    .values()
+                    .filter(|&value| !word_set.has(value))
+                    .count();
+                let yellows: Vec<usize> = word_set
+                    .values()
+                    .filter(|value| !map.contains_key(value))
+                    .take(max_yellows)
+                    .collect();
+                yellows.iter().for_each(|&value| {
+                    map.insert(value, Color::YELLOW);
+                })
+            }
+        });
+
+        let mut colors = vec![Color::BLACK; length];
+
+        map.iter().for_each(|(&key, &value)| {
+            colors[key] = value;
+        });
+        let clues = Clues(colors);
+
+        WordClues { clues, word }
+    }
+
+    pub fn word(&self) -> &'a WordProcessor<'a> {
+        self.word
+    }
+
+    pub fn get_colors(&self) -> &Clues {
+        &self.clues
+    }
+}
+
+impl<'a> From<WordClues<'a>> for Clues {
+    fn from(value: WordClues<'a>) -> Self {
+        value.clues
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameState {
+    pub guesses: Vec<(String, Clues)>,
+}
+
+// A game stored in the database, from entity::game::Model::history
+#[cfg(not(target_arch = "wasm32"))]
+impl TryFrom<Vec<entity::guess::Model>> for GameState {
+    type Error = PuzzleError;
+
+    fn try_from(history: Vec<entity::guess::Model>) -> Result<Self, Self::Error> {
+        let guesses = history
+            .into_iter()
+            .map(|guess| {
+                let clues = Clues::for_word(&guess.word, &guess.clues)?;
+                Ok((guess.word, clues))
+            })
+            .collect::<Result<_, PuzzleError>>()?;
+        Ok(GameState { guesses })
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LetterConstraints {
+    min_counts: HashMap<char, usize>,
+    max_counts: HashMap<char, usize>,
+    known_positions: Vec<Option<char>>,
+    forbidden_positions: HashMap<char, Bitmask>,
+}
+
+impl LetterConstraints {
+    pub fn new() -> Self {
+        LetterConstraints::default()
+    }
+
+    pub fn add(&mut self, word: &WordProcessor, clues: &Clues) {
+        debug_assert_eq!(word.word.chars().count(), clues.len());
+        let mut colored: HashMap<char, usize> = HashMap::with_capacity(clues.len());
+        let mut blacked: HashSet<char> = HashSet::with_capacity(clues.len());
+        let mut grayed: HashSet<char> = HashSet::with_capacity(clues.len());
+        if self.known_positions.len() < clues.len() {
+            self.known_positions.resize(clues.len(), None);
+        }
+
+        word.word.chars().zip(clues.0.iter()).enumerate().for_each(
+            |(idx, (c, color))| match color {
+                Color::GREEN => {
+                    self.known_positions[idx] = Some(c);
+                    *colored.entry(c).or_default() += 1;
+                }
+                Color::YELLOW => {
+                    self.forbidden_positions.entry(c).or_default().add(idx);
+                    *colored.entry(c).or_default() += 1;
+                }
+                Color::BLACK => {
+                    self.forbidden_positions.entry(c).or_default().add(idx);
+                    blacked.insert(c);
+                }
+                Color::GRAY => {
+                    grayed.insert(c);
+                }
+            },
+        );
+
+        colored.iter().for_each(|(&c, &count)| {
+            let min = self.min_counts.entry(c).or_default();
+            *min = (*min).max(count);
+        });
+        blacked.difference(&grayed).for_each(|&c| {
+            let count = colored.get(&c).copied().unwrap_or_default();
+            let max = self.max_counts.entry(c).or_insert(count);
+            *max = (*max).min(count);
+        });
+    }
+
+    pub fn min_count(&self, c: char) -> usize {
+        self.min_counts.get(&c).copied().unwrap_or_default()
+    }
+
+    pub fn max_count(&self, c: char) -> Option<usize> {
+        self.max_counts.get(&c).copied()
+    }
+
+    pub fn known_positions(&self) -> &[Option<char>] {
+        &self.known_positions
+    }
+
+    pub fn forbidden_positions(&self, c: char) -> Option<&Bitmask> {
+        self.forbidden_positions.get(&c)
+    }
+
+    pub fn matches(&self, word: &WordProcessor) -> bool {
+        let count = |c: char| word.get(c).map_or(0, |mask| mask.count() as usize);
+
+        self.known_positions
+            .iter()
+            .enumerate()
+            .all(|(idx, known)| known.is_none_or(|c| word.get(c).is_some_and(|mask| mask.has(idx))))
+            && self.forbidden_positions.iter().all(|(&c, forbidden)| {
+                word.get(c)
+                    .is_none_or(|mask| mask.intersection(forbidden).is_empty())
+            })
+            && self.min_counts.iter().all(|(&c, &min)| count(c) >= min)
+            && self.max_counts.iter().all(|(&c, &max)| count(c) <= max)
+    }
+}
+
+pub struct WordSuggestor<'a> {
+    word_bank: Vec<WordProcessor<'a>>,
+    answers: Option<Vec<WordProcessor<'a>>>,
+    guesses: Vec<(WordProcessor<'a>, Clues)>,
+    hard_mode: bool,
+    matrix: Option<PatternMatrix>,
+}
+
+impl<'a> WordSuggestor<'a> {
+    pub fn new(word_bank: Vec<WordProcessor<'a>>) -> Self {
+        WordSuggestor {
+            word_bank,
+            answers: None,
+            guesses: vec![],
+            hard_mode: false,
+            matrix: None,
+        }
+    }
+
+    // Solutions come from the answer list while every word in the bank can still be guessed.
+    // Without one the word bank serves as both
+    pub fn with_answers(mut self, answers: Vec<WordProcessor<'a>>) -> Self {
+        self.answers = Some(answers);
+        if self.matrix.is_some() {
+            return self.with_matrix();
+        }
+        self
+    }
+
+    // Precomputes every word bank guess against every solution, so that bucket rankers
+    // look clues up instead of working them out. Memory grows with guesses x solutions
+    pub fn with_matrix(mut self) -> Self {
+        let guesses: Vec<&WordProcessor> = self.word_bank.iter().collect();
+        let solutions: Vec<&WordProcessor> = self.solutions().iter().collect();
+        self.matrix = Some(PatternMatrix::new(&guesses, &solutions));
+        self
+    }
+
+    // In hard mode every guess must fit the clues so far, so only words that do are ranked
+    pub fn with_hard_mode(mut self, hard_mode: bool) -> Self {
+        self.hard_mode = hard_mode;
+        self
+    }
+
+    pub fn from_game_state(
+        word_bank: Vec<WordProcessor<'a>>,
+        state: &'a GameState,
+    ) -> Result<Self, PuzzleError> {
+        let mut word_suggestor = WordSuggestor::new(word_bank);
+        for (word, clues) in &state.guesses {
+            word_suggestor.add_guess(word, clues.clone())?;
+        }
+        Ok(word_suggestor)
+    }
+
+    pub fn game_state(&self) -> GameState {
+        GameState {
+            guesses: self
+                .guesses
+                .iter()
+                .map(|(word, clues)| (word.word.to_owned(), clues.clone()))
+                .collect(),
+        }
+    }
+
+    pub fn letter_constraints(&self) -> LetterConstraints {
+        let mut constraints = LetterConstraints::new();
+        for (word, clues) in &self.guesses {
+            constraints.add(word, clues);
+        }
+        constraints
+    }
+
+    pub fn possible_solutions(&self) -> Vec<&WordProcessor<'a>> {
+        let solutions = self.solutions();
+        self.fitting(solutions)
+            .into_iter()
+            .map(|idx| &solutions[idx])
+            .collect()
+    }
+
+    fn solutions(&self) -> &[WordProcessor<'a>] {
+        self.answers.as_deref().unwrap_or(&self.word_bank)
+    }
+
+    // Indices of the words consistent with every clue so far
+    fn fitting(&self, words: &[WordProcessor<'a>]) -> Vec<usize> {
+        let constraints = self.letter_constraints();
+        (0..words.len())
+            .filter(|&idx| constraints.matches(&words[idx]))
+            .filter(|&idx| {
+                self.guesses.iter().all(|(word, clues)| {
+                    clues.matches_partial(WordClues::from_solution(word, &words[idx]).get_colors())
+                })
+            })
+            .collect()
+    }
+
+    pub fn suggest_word<T>(&self, ranker: &T, show_progress: bool) -> String
+    where
+        T: Ranker,
+    {
+        if self.guesses.is_empty() {
+            return self.opener(ranker, show_progress);
+        }
+        self.rank_suggestion(ranker, show_progress)
+    }
+
+    // The first guess only depends on the bank and ranker, so it is ranked once and cached
+    pub fn opener<T>(&self, ranker: &T, show_progress: bool) -> String
+    where
+        T: Ranker,
+    {
+        let key = self.opener_key(ranker);
+        if let Some(opener) = openers().lock().unwrap().get(&key) {
+            return opener.clone();
+        }
+
+        let opener = self.rank_suggestion(ranker, show_progress);
+        openers().lock().unwrap().insert(key, opener.clone());
+        opener
+    }
+
+    // Ranker name plus an FNV-1a hash of the guesses and answers, stable across builds
+    fn opener_key<T: Ranker>(&self, ranker: &T) -> String {
+        let answers = self.answers.as_deref().unwrap_or_default();
+        // An empty line separates the guesses from the answers
+        let words = self.word_bank.iter().map(WordProcessor::word);
+        let answers = answers.iter().map(WordProcessor::word);
+        let hash = words
+            .chain([""])
+            .chain(answers)
+            .flat_map(|word| word.bytes().chain(std::iter::once(b'\n')))
+            .fold(0xcbf29ce484222325u64, |hash, byte| {
+                (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+            });
+        format!("{}:{:016x}", ranker.name(), hash)
+    }
+
+    fn rank_suggestion<T>(&self, ranker: &T, show_progress: bool) -> String
+    where
+        T: Ranker,
+    {
+        info!("Calculating possible solutions");
+        let columns = self.fitting(self.solutions());
+        info!("Number of possible solutions: {}", columns.len());
+
+        if columns.is_empty() {
+            return "".to_owned();
+        }
+
+        if columns.len() == 1 {
+            return self.solutions()[columns[0]].word.to_owned();
+        }
+
+        info!("Calculating suggestion");
+        let progress_bar = if show_progress {
+            ProgressBar::new(0)
+        } else {
+            ProgressBar::hidden()
+        };
+        let (suggestion, _) = self.scores(ranker, &columns, &progress_bar)[0];
+
+        suggestion.word.to_owned()
+    }
+
+    // The best n guesses with their scores, in the order suggest_word prefers them
+    pub fn suggest_top_n<T>(&self, ranker: &T, n: usize) -> Vec<(String, usize)>
+    where
+        T: Ranker,
+    {
+        let columns = self.fitting(self.solutions());
+        if columns.is_empty() {
+            return vec![];
+        }
+
+        self.scores(ranker, &columns, &ProgressBar::hidden())
+            .into_iter()
+            .take(n)
+            .map(|(word, score)| (word.word.to_owned(), score))
+            .collect()
+    }
+
+    // Word bank indices worth ranking as the next guess
+    fn candidates(&self) -> Vec<usize> {
+        if self.hard_mode {
+            self.fitting(&self.word_bank)
+        } else {
+            (0..self.word_bank.len()).collect()
+        }
+    }
+
+    // Highest score first. Ties go to words that could be the solution, then alphabetically.
+    // `columns` are the possible solutions' indices, which with a pattern matrix let bucket
+    // rankers score each candidate by table lookup
+    fn scores<'b, T>(
+        &'b self,
+        ranker: &T,
+        columns: &[usize],
+        progress_bar: &ProgressBar,
+    ) -> Vec<(&'b WordProcessor<'a>, usize)>
+    where
+        T: Ranker,
+    {
+        let solutions = self.solutions();
+        let possible_solutions: Vec<&WordProcessor> =
+            columns.iter().map(|&idx| &solutions[idx]).collect();
+        let candidates = self.candidates();
+        // An answer list need not be part of the word bank
+        if candidates.is_empty() {
+            progress_bar.set_length(possible_solutions.len() as u64);
+            return Self::sort_scores(
+                possible_solutions.clone(),
+                &possible_solutions,
+                progress_bar,
+                |word, _| ranker.rank(&possible_solutions, word),
+            );
+        }
+
+        progress_bar.set_length(candidates.len() as u64);
+        let words = candidates.iter().map(|&idx| &self.word_bank[idx]).collect();
+        match (&self.matrix, ranker.as_bucket_ranker()) {
+            (Some(matrix), Some(bucket_ranker)) => {
+                Self::sort_scores(words, &possible_solutions, progress_bar, |_, i| {
+                    matrix.rank(bucket_ranker, candidates[i], columns)
+                })
+            }
+            _ => Self::sort_scores(words, &possible_solutions, progress_bar, |word, _| {
+                ranker.rank(&possible_solutions, word)
+            }),
+        }
+    }
+
+    // `rank` gets each word along with its position in `words`
+    fn sort_scores<'b>(
+        words: Vec<&'b WordProcessor<'a>>,
+        possible_solutions: &[&'b WordProcessor<'a>],
+        progress_bar: &ProgressBar,
+        rank: impl Fn(&WordProcessor, usize) -> usize + Sync + Send,
+    ) -> Vec<(&'b WordProcessor<'a>, usize)> {
+        #[cfg(not(target_arch = "wasm32"))]
+        let words = words.into_par_iter().enumerate();
+        #[cfg(target_arch = "wasm32")]
+        let words = words.into_iter().enumerate();
+        let mut scores: Vec<(&WordProcessor, usize)> = words
+            .map(|(i, word)| {
+                progress_bar.inc(1);
+                (word, rank(word, i))
+            })
+            .collect();
+
+        let possible: HashSet<&str> = possible_solutions.iter().map(|word| word.word).collect();
+        scores.sort_by_key(|&(word, score)| {
+            (Reverse(score), !possible.contains(word.word), word.word)
+        });
+        scores
+    }
+
+    pub fn add_clue(&mut self, word_clue: &WordClues<'a>) {
+        self.guesses
+            .push((word_clue.word.clone(), word_clue.clues.clone()));
+    }
+
+    // The clues must have one color per letter of the guess
+    pub fn add_guess(&mut self, word: &'a str, clues: Clues) -> Result<(), PuzzleError> {
+        let clues = clues.expect_len(word.chars().count())?;
+        self.guesses.push((WordProcessor::new(word), clues));
+        Ok(())
+    }
+}
+
+pub trait Ranker: Sync + Send {
+    // Identifies the ranker in the opener cache, so it must stay the same across builds
+    fn name(&self) -> &'static str;
+
+    fn rank(&self, possible_solutions: &[&WordProcessor], word: &WordProcessor) -> usize;
+
+    // Some for rankers that can score from a PatternMatrix
+    fn as_bucket_ranker(&self) -> Option<&dyn BucketRanker> {
+        None
+    }
+}
+
+// Rankers that only care how a guess splits the possible solutions by clue, given the
+// size of each bucket. These can also rank from a PatternMatrix
+pub trait BucketRanker: Sync + Send {
+    fn name(&self) -> &'static str;
+
+    fn rank_buckets(&self, total: usize, buckets: &[usize]) -> usize;
+}
+
+impl<T: BucketRanker> Ranker for T {
+    fn name(&self) -> &'static str {
+        BucketRanker::name(self)
+    }
+
+    fn rank(&self, possible_solutions: &[&WordProcessor], word: &WordProcessor) -> usize {
+        let mut map = HashMap::<Clues, usize>::new();
+        possible_solutions.iter().for_each(|solution| {
+            let word_clues = WordClues::from_solution(word, solution);
+            *map.entry(word_clues.into()).or_default() += 1;
+        });
+        let buckets: Vec<usize> = map.into_values().collect();
+        self.rank_buckets(possible_solutions.len(), &buckets)
+    }
+
+    fn as_bucket_ranker(&self) -> Option<&dyn BucketRanker> {
+        Some(self)
+    }
+}
+
+// Clue patterns for every guess against every solution, computed once so that ranking
+// is a table lookup. Memory grows with guesses x solutions
+pub struct PatternMatrix {
+    solutions: usize,
+    patterns: Vec<u32>,
+}
+
+impl PatternMatrix {
+    pub fn new(guesses: &[&WordProcessor], solutions: &[&WordProcessor]) -> Self {
+        let row = |guess: &&WordProcessor| -> Vec<u32> {
+            solutions
+                .iter()
+                .map(|solution| Clues::from(WordClues::from_solution(guess, solution)).pattern())
+                .collect()
+        };
+        #[cfg(not(target_arch = "wasm32"))]
+        let rows: Vec<Vec<u32>> = guesses.par_iter().map(row).collect();
+        #[cfg(target_arch = "wasm32")]
+        let rows: Vec<Vec<u32>> = guesses.iter().map(row).collect();
+
+        PatternMatrix {
+            solutions: solutions.len(),
+            patterns: rows.concat(),
+        }
+    }
+
+    pub fn pattern(&self, guess: usize, solution: usize) -> u32 {
+        self.patterns[guess * self.solutions + solution]
+    }
+
+    // Ranks a guess against the solutions, by column, that are still possible
+    pub fn rank<T: BucketRanker + ?Sized>(
+        &self,
+        ranker: &T,
+        guess: usize,
+        solutions: &[usize],
+    ) -> usize {
+        let mut map = HashMap::<u32, usize>::new();
+        solutions.iter().for_each(|&solution| {
+            *map.entry(self.pattern(guess, solution)).or_default() += 1;
+        });
+        let buckets: Vec<usize> = map.into_values().collect();
+        ranker.rank_buckets(solutions.len(), &buckets)
+    }
+}
+
+pub struct LowestMaxBucketRanker;
+
+impl LowestMaxBucketRanker {
+    pub fn new() -> Self {
+        LowestMaxBucketRanker {}
+    }
+}
+
+impl Default for LowestMaxBucketRanker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BucketRanker for LowestMaxBucketRanker {
+    fn name(&self) -> &'static str {
+        "LowestMaxBucketRanker"
+    }
+
+    fn rank_buckets(&self, total: usize, buckets: &[usize]) -> usize {
+        total - buckets.iter().max().unwrap()
+    }
+}
+
+pub struct LargestUniqueValuesRanker;
+
+impl LargestUniqueValuesRanker {
+    pub fn new() -> Self {
+        LargestUniqueValuesRanker {}
+    }
+}
+
+impl Default for LargestUniqueValuesRanker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BucketRanker for LargestUniqueValuesRanker {
+    fn name(&self) -> &'static str {
+        "LargestUniqueValuesRanker"
+    }
+
+    fn rank_buckets(&self, _total: usize, buckets: &[usize]) -> usize {
+        buckets.len()
+    }
+}
+
+// Expected information from the clue pattern, in millibits so it still ranks as a usize
+pub struct EntropyRanker;
+
+impl EntropyRanker {
+    pub fn new() -> Self {
+        EntropyRanker {}
+    }
+}
+
+impl Default for EntropyRanker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BucketRanker for EntropyRanker {
+    fn name(&self) -> &'static str {
+        "EntropyRanker"
+    }
+
+    fn rank_buckets(&self, total: usize, buckets: &[usize]) -> usize {
+        let total = total as f64;
+        let bits: f64 = buckets
+            .iter()
+            .map(|&count| {
+                let p = count as f64 / total;
+                -p * p.log2()
+            })
+            .sum();
+        (bits * 1000.0).round() as usize
+    }
+}
+
+static OPENERS: OnceLock<Mutex<HashMap<String, String>>> = OnceLock::new();
+
+// Seeded with openers ranked ahead of time for the bundled word bank
+fn openers() -> &'static Mutex<HashMap<String, String>> {
+    OPENERS.get_or_init(|| {
+        Mutex::new(
+            serde_json::from_str(include_str!("openers.json"))
+                .expect("bundled openers.json is valid"),
+        )
+    })
+}
+
+const VOWELS: [char; 5] = ['a', 'e', 'i', 'o', 'u'];
+const COMMON_CONSONANTS: [char; 5] = ['r', 's', 't', 'l', 'n'];
+
+pub struct VowelCoverageRanker;
+
+impl VowelCoverageRanker {
+    pub fn new() -> Self {
+        VowelCoverageRanker {}
+    }
+}
+
+impl Default for VowelCoverageRanker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Ranker for VowelCoverageRanker {
+    fn name(&self) -> &'static str {
+        "VowelCoverageRanker"
+    }
+
+    fn rank(&self, _possible_solutions: &[&WordProcessor], word: &WordProcessor) -> usize {
+        VOWELS
+            .iter()
+            .chain(COMMON_CONSONANTS.iter())
+            .filter(|&&c| word.get(c).is_some())
+            .count()
+    }
+}
+
+pub fn best_opener<'a>(bank: &[&'a str]) -> &'a str {
+    let ranker = VowelCoverageRanker::new();
+    bank.iter()
+        .max_by_key(|word| ranker.rank(&[], &WordProcessor::new(word)))
+        .copied()
+        .unwrap_or_default()
+}
+
+pub fn suggest_from_json(bank_json: &str, clues_json: &str) -> Result<String, PuzzleError> {
+    let bank: Vec<String> =
+        serde_json::from_str(bank_json).map_err(|err| PuzzleError::parse(err.to_string()))?;
+    let guesses: Vec<(String, String)> =
+        serde_json::from_str(clues_json).map_err(|err| PuzzleError::parse(err.to_string()))?;
+
+    suggest(bank.iter().map(String::as_str), &guesses)
+}
+
+pub fn suggest<'a>(
+    bank: impl IntoIterator<Item = &'a str>,
+    guesses: &'a [(String, String)],
+) -> Result<String, PuzzleError> {
+    let mut word_suggestor = WordSuggestor::new(bank.into_iter().map(WordProcessor::new).collect());
+    for (word, clues) in guesses {
+        word_suggestor.add_guess(word, Clues::for_word(word, clues)?)?;
+    }
+
+    Ok(word_suggestor.suggest_word(&LowestMaxBucketRanker::new(), false))
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen::prelude::wasm_bindgen]
+pub fn suggest_word(bank_json: &str, clues_json: &str) -> Result<String, wasm_bindgen::JsValue> {
+    suggest_from_json(bank_json, clues_json)
+        .map_err(|err| wasm_bindgen::JsValue::from_str(&err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod bitmask {
+        use super::*;
+
+        #[test]
+        fn test_init() {
+            let mask = Bitmask::new();
+            assert_eq!(mask.0, 0);
+        }
+
+        #[test]
+        fn test_add() {
+            let mut mask = Bitmask::new();
+            mask.add(0);
+            assert_eq!(mask.0, 1);
+
+            mask.add(2);
+            assert_eq!(mask.0, 5);
+        }
+
+        #[test]
+        fn test_remove() {
+            let mut mask = Bitmask::new();
+            mask.add(3);
+            assert_eq!(mask.0, 8);
+
+            mask.remove(3);
+            assert_eq!(mask.0, 0);
+        }
+
+        #[test]
+        fn test_muli_add() {
+            let mut mask = Bitmask::new();
+            mask.add(3);
+            assert_eq!(mask.0, 8);
+
+            mask.add(3);
+            assert_eq!(mask.0, 8);
+        }
+
+        #[test]
+        fn test_muli_remove() {
+            let mut mask = Bitmask::new();
+            mask.add(3);
+            assert_eq!(mask.0, 8);
+
+            mask.remove(3);
+            assert_eq!(mask.0, 0);
+
+            mask.remove(3);
+            assert_eq!(mask.0, 0);
+        }
+
+        #[test]
+        fn test_values() {
+            let mut mask = Bitmask::new();
+            mask.add(3);
+            mask.add(8);
+
+            let values: Vec<usize> = mask.values().collect();
+            println!("Values: {:?}", values);
+            assert!(values.contains(&3));
+            assert!(values.contains(&8));
+        }
+
+        #[test]
+        fn test_values_reverse() {
+            let mut mask = Bitmask::new();
+            mask.add(3);
+            mask.add(8);
+            mask.add(5);
+
+            let values: Vec<usize> = mask.values().rev().collect();
+            assert_eq!(values, vec![8, 5, 3]);
+        }
+
+        #[test]
+        fn test_from_positions() {
+            let mask = Bitmask::from_positions(&[3, 8]);
+            assert_eq!(mask.0, 264);
+
+            let values: Vec<usize> = mask.values().collect();
+            assert_eq!(values, vec![3, 8]);
+        }
+
+        #[test]
+        fn test_intersection() {
+            let first = Bitmask::from_positions(&[1, 2, 5, 7]);
+            let second = Bitmask::from_positions(&[2, 5, 6, 8]);
+
+            let intersection = first.intersection(&second);
+
+            assert!(intersection.has(2));
+            assert!(intersection.has(5));
+        }
+
+        #[test]
+        fn test_difference() {
+            let first = Bitmask::from_positions(&[1, 2, 5, 7]);
+            let second = Bitmask::from_positions(&[2, 5, 6, 8]);
+
+            let intersection = first.symmetric_difference(&second);
+
+            assert!(intersection.has(1));
+            assert!(intersection.has(6));
+            assert!(intersection.has(7));
+            assert!(intersection.has(8));
+        }
+
+        #[test]
+        fn test_union() {
+            let first = Bitmask::from_positions(&[1, 2, 5, 7]);
+            let second = Bitmask::from_positions(&[2, 5, 6, 8]);
+
+            let union = first.union(&second);
+
+            assert_eq!(
+                union.values().collect::<Vec<usize>>(),
+                vec![1, 2, 5, 6, 7, 8]
+            );
+        }
+
+        #[test]
+        fn test_range() {
+            let mut mask = Bitmask::new();
+
+            assert_eq!(mask.try_add(63), Ok(()));
+            assert!(mask.has(63));
+            assert_eq!(mask.try_add(64), Err(BitmaskRangeError(64)));
+            assert!(!mask.has(64));
+            assert_eq!(mask.values().collect::<Vec<usize>>(), vec![63]);
+            assert_eq!(
+                BitmaskRangeError(64).to_string(),
+                "Position 64 does not fit in a 64 bit mask"
+            );
+        }
+
+        #[cfg(debug_assertions)]
+        #[test]
+        #[should_panic(expected = "Position 64 does not fit")]
+        fn test_add_out_of_range() {
+            Bitmask::new().add(64);
+        }
+
+        #[test]
+        fn test_operators() {
+            let first = Bitmask::from_positions(&[1, 2, 5, 7]);
+            let second = Bitmask::from_positions(&[2, 5, 6, 8]);
+
+            assert_eq!(first & second, first.intersection(&second));
+            assert_eq!(first | second, first.union(&second));
+            assert_eq!(first ^ second, first.symmetric_difference(&second));
+
+            let mut mask = first;
+            mask &= second;
+            assert_eq!(mask, first & second);
+            mask |= first;
+            assert_eq!(mask, first);
+            mask ^= first;
+            assert!(mask.is_empty());
+        }
+
+        #[test]
+        fn test_count() {
+            assert_eq!(Bitmask::new().count(), 0);
+            assert_eq!(Bitmask::from_positions(&[1, 2, 5, 7]).count(), 4);
+            assert_eq!(Bitmask::from_positions(&[3, 3]).count(), 1);
+        }
+
+        #[test]
+        fn test_is_empty() {
+            let mut mask = Bitmask::new();
+            assert!(mask.is_empty());
+
+            mask.add(4);
+            assert!(!mask.is_empty());
+
+            mask.remove(4);
+            assert!(mask.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_repeated_letters() {
+        let colors = |word: &str, solution: &str| {
+            Clues::from(WordClues::from_solution(
+                &WordProcessor::new(word),
+                &WordProcessor::new(solution),
+            ))
+        };
+        let clues = |clues: &str| clues.parse::<Clues>().unwrap();
+
+        // Two of a letter against one: only the first unmatched copy is yellow
+        assert_eq!(colors("speed", "abide"), clues("bbyby"));
+        assert_eq!(colors("llama", "label"), clues("gyybb"));
+        // Both copies matched green leaves the third black
+        assert_eq!(colors("eerie", "delve"), clues("bgbbg"));
+        // One of a letter against two
+        assert_eq!(colors("label", "llama"), clues("gybby"));
+    }
+
+    #[test]
+    fn test_colors() {
+        assert_eq!(
+            *WordClues::from_solution(&WordProcessor::new(&"saber"), &WordProcessor::new("label"))
+                .get_colors(),
+            Clues(vec![
+                Color::BLACK,
+                Color::GREEN,
+                Color::GREEN,
+                Color::GREEN,
+                Color::BLACK
+            ])
+        );
+        assert_eq!(
+            *WordClues::from_solution(&WordProcessor::new(&"aheap"), &WordProcessor::new(&"woken"))
+                .get_colors(),
+            Clues(vec![
+                Color::BLACK,
+                Color::BLACK,
+                Color::YELLOW,
+                Color::BLACK,
+                Color::BLACK
+            ])
+        );
+
+        assert_eq!(
+            *WordClues::from_solution(&WordProcessor::new(&"serai"), &WordProcessor::new(&"delve"))
+                .get_colors(),
+            Clues(vec![
+                Color::BLACK,
+                Color::GREEN,
+                Color::BLACK,
+                Color::BLACK,
+                Color::BLACK
+            ])
+        );
+        assert_eq!(
+            *WordClues::from_solution(&WordProcessor::new(&"yente"), &WordProcessor::new(&"delve"))
+                .get_colors(),
+            Clues(vec![
+                Color::BLACK,
+                Color::GREEN,
+                Color::BLACK,
+                Color::BLACK,
+                Color::GREEN
+            ])
+        );
+        assert_eq!(
+            *WordClues::from_solution(&WordProcessor::new(&"blech"), &WordProcessor::new(&"delve"))
+                .get_colors(),
+            Clues(vec![
+                Color::BLACK,
+                Color::YELLOW,
+                Color::YELLOW,
+                Color::BLACK,
+                Color::BLACK
+            ])
+        );
+        assert_eq!(
+            *WordClues::from_solution(&WordProcessor::new(&"begem"), &WordProcessor::new(&"delve"))
+                .get_colors(),
+            Clues(vec![
+                Color::BLACK,
+                Color::GREEN,
+                Color::BLACK,
+                Color::YELLOW,
+                Color::BLACK
+            ])
+        );
+        assert_eq!(
+            *WordClues::from_solution(&WordProcessor::new(&"welke"), &WordProcessor::new(&"delve"))
+                .get_colors(),
+            Clues(vec![
+                Color::BLACK,
+                Color::GREEN,
+                Color::GREEN,
+                Color::BLACK,
+                Color::GREEN
+            ])
+        );
+        assert_eq!(
+            *WordClues::from_solution(&WordProcessor::new(&"mommy"), &WordProcessor::new(&"delve"))
+                .get_colors(),
+            Clues(vec![
+                Color::BLACK,
+                Color::BLACK,
+                Color::BLACK,
+                Color::BLACK,
+                Color::BLACK
+            ])
+        );
+
+        assert_eq!(
+            *WordClues::from_solution(&WordProcessor::new(&"forge"), &WordProcessor::new(&"forge"))
+                .get_colors(),
+            Clues(vec![Color::GREEN; 5])
+        );
+        assert_eq!(
+            *WordClues::from_solution(&WordProcessor::new(&"forte"), &WordProcessor::new(&"forge"))
+                .get_colors(),
+            Clues(vec![
+                Color::GREEN,
+                Color::GREEN,
+                Color::GREEN,
+                Color::BLACK,
+                Color::GREEN
+            ])
+        );
+        assert_eq!(
+            *WordClues::from_solution(&WordProcessor::new(&"forze"), &WordProcessor::new(&"forge"))
+                .get_colors(),
+            Clues(vec![
+                Color::GREEN,
+                Color::GREEN,
+                Color::GREEN,
+                Color::BLACK,
+                Color::GREEN
+            ])
+        );
+        assert_eq!(
+            *WordClues::from_solution(&WordProcessor::new(&"bafts"), &WordProcessor::new(&"forge"))
+                .get_colors(),
+            Clues(vec![
+                Color::BLACK,
+                Color::BLACK,
+                Color::YELLOW,
+                Color::BLACK,
+                Color::BLACK
+            ])
+        );
+        assert_eq!(
+            *WordClues::from_solution(&WordProcessor::new(&"murid"), &WordProcessor::new(&"forge"))
+                .get_colors(),
+            Clues(vec![
+                Color::BLACK,
+                Color::BLACK,
+                Color::GREEN,
+                Color::BLACK,
+                Color::BLACK
+            ])
+        );
+        assert_eq!(
+            *WordClues::from_solution(&WordProcessor::new(&"soare"), &WordProcessor::new(&"forge"))
+                .get_colors(),
+            Clues(vec![
+                Color::BLACK,
+                Color::GREEN,
+                Color::BLACK,
+                Color::YELLOW,
+                Color::GREEN
+            ])
+        );
+    }
+
+    mod clues {
+        use super::*;
+
+        #[test]
+        fn test_parse() {
+            assert_eq!(
+                "bygbb\r".parse::<Clues>().unwrap(),
+                Clues(vec![
+                    Color::BLACK,
+                    Color::YELLOW,
+                    Color::GREEN,
+                    Color::BLACK,
+                    Color::BLACK
+                ])
+            );
+            assert_eq!(
+                "bbbbb".parse::<Clues>().unwrap(),
+                Clues(vec![Color::BLACK; 5])
+            );
+        }
+
+        #[test]
+        fn test_parse_gray() {
+            assert_eq!(
+                "b?g?b".parse::<Clues>().unwrap(),
+                Clues(vec![
+                    Color::BLACK,
+                    Color::GRAY,
+                    Color::GREEN,
+                    Color::GRAY,
+                    Color::BLACK
+                ])
+            );
+        }
+
+        #[test]
+        fn test_matches_partial() {
+            let full: Clues = "bygbb".parse().unwrap();
+
+            assert!("bygbb".parse::<Clues>().unwrap().matches_partial(&full));
+            assert!("b?g?b".parse::<Clues>().unwrap().matches_partial(&full));
+            assert!("?????".parse::<Clues>().unwrap().matches_partial(&full));
+            assert!(!"g?g?b".parse::<Clues>().unwrap().matches_partial(&full));
+        }
+
+        #[test]
+        fn test_solved() {
+            assert!("ggggg".parse::<Clues>().unwrap().is_solved());
+            assert!(!"ggggy".parse::<Clues>().unwrap().is_solved());
+            assert!(!"gggg?".parse::<Clues>().unwrap().is_solved());
+        }
+
+        #[test]
+        fn test_display_round_trip() {
+            for pattern in ["bbbbb", "ggggg", "bygbb", "y?g?b", "gyg"] {
+                let clues = Clues::for_word(pattern, pattern).unwrap();
+
+                assert_eq!(clues.to_string(), pattern);
+                assert_eq!(Clues::for_word(pattern, &clues.to_string()).unwrap(), clues);
+            }
+        }
+
+        #[cfg(feature = "ansi")]
+        #[test]
+        fn test_display_ansi() {
+            let clues = Clues::for_word("gb", "gb").unwrap();
+
+            assert_eq!(
+                format!("{:#}", clues),
+                "\x1b[32m\u{25a0}\x1b[0m\x1b[30m\u{25a0}\x1b[0m"
+            );
+            assert_eq!(format!("{}", clues), "gb");
+        }
+
+        #[test]
+        fn test_parse_errors() {
+            assert!(matches!(
+                "byxbb".parse::<Clues>(),
+                Err(PuzzleError::Parse { .. })
+            ));
+            assert!(matches!(
+                "".parse::<Clues>(),
+                Err(PuzzleError::Parse { .. })
+            ));
+            assert!(matches!(
+                Clues::for_word("serai", "byg"),
+                Err(PuzzleError::Parse { .. })
+            ));
+            assert!(matches!(
+                Clues::for_word("serai", "bygbbg"),
+                Err(PuzzleError::Parse { .. })
+            ));
+            assert_eq!(
+                Clues::for_word("serai", "bbbb").unwrap_err().to_string(),
+                "Could not parse puzzle: Expected 5 colors, found 4"
+            );
+            assert_eq!(
+                "bxbby".parse::<Clues>().unwrap_err().to_string(),
+                "Could not parse puzzle: Unsupported color x"
+            );
+            assert_eq!(
+                "bbbb".parse::<Clues>().unwrap_err().to_string(),
+                "Could not parse puzzle: Expected 5 colors, found 4"
+            );
+            assert!("bbbbb".parse::<Clues>().is_ok());
+        }
+
+        #[test]
+        fn test_mismatched_length() {
+            let four = Clues::for_word("lamp", "bgby").unwrap();
+            let five: Clues = "bgbyb".parse().unwrap();
+            let mut word_suggestor = WordSuggestor::new(vec![WordProcessor::new("serai")]);
+
+            assert!(matches!(
+                word_suggestor.add_guess("serai", four.clone()),
+                Err(PuzzleError::Parse { .. })
+            ));
+            assert!(word_suggestor.game_state().guesses.is_empty());
+            assert!(!four.matches_partial(&five));
+            assert!(!five.matches_partial(&four));
+        }
+    }
+
+    mod entropy {
+        use super::*;
+
+        const SOLUTIONS: [&str; 4] = ["cigar", "rebut", "sissy", "humph"];
+
+        fn rank(word: &str) -> usize {
+            let solutions: Vec<WordProcessor> = SOLUTIONS
+                .iter()
+                .map(|word| WordProcessor::new(word))
+                .collect();
+            let solutions: Vec<&WordProcessor> = solutions.iter().collect();
+            EntropyRanker::new().rank(&solutions, &WordProcessor::new(word))
+        }
+
+        #[test]
+        fn test_no_information() {
+            assert_eq!(rank("jjjjj"), 0);
+        }
+
+        #[test]
+        fn test_splits_every_solution() {
+            // Four equal buckets are exactly two bits
+            assert_eq!(rank("cigar"), 2000);
+        }
+
+        #[test]
+        fn test_prefers_informative_guess() {
+            // "jazzy" cannot tell "rebut" from "humph"
+            assert_eq!(rank("jazzy"), 1500);
+            assert!(rank("cigar") > rank("jazzy"));
+        }
+    }
+
+    mod top_n {
+        use super::*;
+
+        const BANK: [&str; 5] = ["forge", "serai", "mount", "cigar", "rebut"];
+
+        #[test]
+        fn test_sorted_and_limited() {
+            let word_suggestor = WordSuggestor::new(BANK.map(WordProcessor::new).to_vec());
+
+            for n in [0, 3, 5, 10] {
+                let top = word_suggestor.suggest_top_n(&EntropyRanker::new(), n);
+
+                assert_eq!(top.len(), n.min(BANK.len()));
+                assert!(top.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+            }
+        }
+
+        #[test]
+        fn test_best_score() {
+            let words: Vec<WordProcessor> = BANK.map(WordProcessor::new).to_vec();
+            let word_suggestor = WordSuggestor::new(words.clone());
+            let ranker = LowestMaxBucketRanker::new();
+            let top = word_suggestor.suggest_top_n(&ranker, 1);
+
+            let solutions: Vec<&WordProcessor> = words.iter().collect();
+            let best = words.iter().map(|word| ranker.rank(&solutions, word)).max();
+            assert_eq!(Some(top[0].1), best);
+        }
+    }
+
+    mod tie_break {
+        use super::*;
+
+        fn word_suggestor(bank: &[&'static str]) -> WordSuggestor<'static> {
+            let answers = ["fills", "bills"];
+            let mut word_suggestor =
+                WordSuggestor::new(bank.iter().map(|w| WordProcessor::new(w)).collect())
+                    .with_answers(answers.map(WordProcessor::new).to_vec());
+            word_suggestor
+                .add_guess("serai", "ybbby".parse().unwrap())
+                .unwrap();
+            word_suggestor
+        }
+
+        #[test]
+        fn test_prefers_possible_solution() {
+            // All three split the two answers, but only the answers could also win outright
+            let ranker = LowestMaxBucketRanker::new();
+            for bank in [["bumph", "fills", "bills"], ["fills", "bills", "bumph"]] {
+                let word_suggestor = word_suggestor(&bank);
+
+                assert_eq!(word_suggestor.suggest_word(&ranker, false), "bills");
+                assert_eq!(
+                    word_suggestor.suggest_top_n(&ranker, 3),
+                    vec![
+                        ("bills".to_owned(), 1),
+                        ("fills".to_owned(), 1),
+                        ("bumph".to_owned(), 1)
+                    ]
+                );
+            }
+        }
+
+        #[test]
+        fn test_stable_across_runs() {
+            let word_suggestor = word_suggestor(&["fills", "bumph", "bills"]);
+            let ranker = LowestMaxBucketRanker::new();
+
+            for _ in 0..20 {
+                assert_eq!(word_suggestor.suggest_word(&ranker, false), "bills");
+            }
+        }
+    }
+
+    mod opener_cache {
+        use super::*;
+
+        const BANK: [&str; 6] = ["cigar", "rebut", "sissy", "humph", "forge", "serai"];
+
+        #[test]
+        fn test_cached_matches_fresh() {
+            let word_suggestor = WordSuggestor::new(BANK.map(WordProcessor::new).to_vec());
+            let ranker = EntropyRanker::new();
+            let key = word_suggestor.opener_key(&EntropyRanker::new());
+
+            let opener = word_suggestor.opener(&ranker, false);
+            assert_eq!(openers().lock().unwrap().get(&key), Some(&opener));
+            assert_eq!(word_suggestor.suggest_word(&ranker, false), opener);
+            assert_eq!(word_suggestor.rank_suggestion(&ranker, false), opener);
+        }
+
+        #[test]
+        fn test_keyed_by_bank_and_ranker() {
+            let word_suggestor = || WordSuggestor::new(BANK.map(WordProcessor::new).to_vec());
+            let key = word_suggestor().opener_key(&EntropyRanker::new());
+            let smaller =
+                WordSuggestor::new(BANK[1..].iter().map(|w| WordProcessor::new(w)).collect());
+            let answers = word_suggestor().with_answers(vec![WordProcessor::new("forge")]);
+
+            assert_eq!(key, word_suggestor().opener_key(&EntropyRanker::new()));
+            assert_ne!(
+                key,
+                word_suggestor().opener_key(&LowestMaxBucketRanker::new())
+            );
+            assert_ne!(key, smaller.opener_key(&EntropyRanker::new()));
+            assert_ne!(key, answers.opener_key(&EntropyRanker::new()));
+        }
+
+        #[test]
+        fn test_bundled_bank_is_cached() {
+            let word_bank = include_str!("word_bank.txt")
+                .lines()
+                .map(WordProcessor::new)
+                .collect();
+            let key = WordSuggestor::new(word_bank).opener_key(&LowestMaxBucketRanker::new());
+
+            assert_eq!(
+                openers().lock().unwrap().get(&key).map(String::as_str),
+                Some("serai")
+            );
+        }
+
+        // Ranks the whole bundled bank, so run with `cargo test --release -- --ignored`
+        #[test]
+        #[ignore]
+        fn test_bundled_opener_is_fresh() {
+            let word_bank = include_str!("word_bank.txt")
+                .lines()
+                .map(WordProcessor::new)
+                .collect();
+            let word_suggestor = WordSuggestor::new(word_bank);
+            let ranker = LowestMaxBucketRanker::new();
+            let key = word_suggestor.opener_key(&ranker);
+
+            assert_eq!(
+                openers().lock().unwrap().get(&key),
+                Some(&word_suggestor.rank_suggestion(&ranker, false))
+            );
+        }
+    }
+
+    mod pattern_matrix {
+        use super::*;
+
+        const WORDS: [&str; 6] = ["cigar", "rebut", "sissy", "humph", "forge", "serai"];
+
+        #[test]
+        fn test_pattern() {
+            let pattern = |clues: &str| clues.parse::<Clues>().unwrap().pattern();
+
+            assert_eq!(pattern("bbbbb"), pattern("bbbbb"));
+            assert_ne!(pattern("bbbby"), pattern("ybbbb"));
+            assert_ne!(pattern("bygbb"), pattern("bgybb"));
+        }
+
+        #[test]
+        fn test_matches_rankers() {
+            let words: Vec<WordProcessor> = WORDS.map(WordProcessor::new).to_vec();
+            let words: Vec<&WordProcessor> = words.iter().collect();
+            let matrix = PatternMatrix::new(&words, &words);
+            // Rank against a subset of the solutions, as after a guess
+            let columns = [0, 2, 3, 4];
+            let solutions: Vec<&WordProcessor> = columns.iter().map(|&idx| words[idx]).collect();
+
+            for (guess, word) in words.iter().enumerate() {
+                assert_eq!(
+                    matrix.rank(&LowestMaxBucketRanker::new(), guess, &columns),
+                    LowestMaxBucketRanker::new().rank(&solutions, word)
+                );
+                assert_eq!(
+                    matrix.rank(&LargestUniqueValuesRanker::new(), guess, &columns),
+                    LargestUniqueValuesRanker::new().rank(&solutions, word)
+                );
+                assert_eq!(
+                    matrix.rank(&EntropyRanker::new(), guess, &columns),
+                    EntropyRanker::new().rank(&solutions, word)
+                );
+            }
+        }
+
+        #[test]
+        fn test_with_matrix() {
+            let bank: Vec<WordProcessor> = include_str!("word_bank.txt")
+                .lines()
+                .take(300)
+                .map(WordProcessor::new)
+                .collect();
+            let answers = bank[100..200].to_vec();
+            let solution = &answers[17];
+            let clues: Clues =
+                WordClues::from_solution(&WordProcessor::new("mummy"), solution).into();
+
+            for hard_mode in [false, true] {
+                let mut plain = WordSuggestor::new(bank.clone())
+                    .with_answers(answers.clone())
+                    .with_hard_mode(hard_mode);
+                let mut matrix = WordSuggestor::new(bank.clone())
+                    .with_matrix()
+                    .with_answers(answers.clone())
+                    .with_hard_mode(hard_mode);
+                for word_suggestor in [&mut plain, &mut matrix] {
+                    word_suggestor.add_guess("mummy", clues.clone()).unwrap();
+                }
+                assert!(matrix.possible_solutions().len() > 10);
+
+                assert_eq!(
+                    matrix.suggest_top_n(&LowestMaxBucketRanker::new(), 10),
+                    plain.suggest_top_n(&LowestMaxBucketRanker::new(), 10)
+                );
+                assert_eq!(
+                    matrix.suggest_top_n(&EntropyRanker::new(), 10),
+                    plain.suggest_top_n(&EntropyRanker::new(), 10)
+                );
+                assert_eq!(
+                    matrix.suggest_word(&VowelCoverageRanker::new(), false),
+                    plain.suggest_word(&VowelCoverageRanker::new(), false)
+                );
+            }
+        }
+    }
+
+    mod opener {
+        use super::*;
+
+        #[test]
+        fn test_vowel_coverage_rank() {
+            let ranker = VowelCoverageRanker::new();
+            assert_eq!(ranker.rank(&[], &WordProcessor::new("adieu")), 4);
+            assert_eq!(ranker.rank(&[], &WordProcessor::new("arise")), 5);
+            assert_eq!(ranker.rank(&[], &WordProcessor::new("mummy")), 1);
+        }
+
+        #[test]
+        fn test_best_opener() {
+            let bank: Vec<&str> = include_str!("word_bank.txt").lines().collect();
+            let ranker = VowelCoverageRanker::new();
+            let score = |word: &str| ranker.rank(&[], &WordProcessor::new(word));
+
+            let best = best_opener(&bank);
+            assert!(bank.iter().all(|word| score(word) <= score(best)));
+
+            let adieu = score("adieu");
+            let better = bank.iter().filter(|word| score(word) > adieu).count();
+            assert!(better * 20 < bank.len());
+        }
+    }
+
+    mod letter_constraints {
+        use super::*;
+
+        #[test]
+        fn test_double_letter() {
+            let guess = WordProcessor::new("eerie");
+            let solution = WordProcessor::new("there");
+            let clues: Clues = WordClues::from_solution(&guess, &solution).into();
+
+            let mut constraints = LetterConstraints::new();
+            constraints.add(&guess, &clues);
+
+            assert_eq!(constraints.min_count('e'), 2);
+            assert_eq!(constraints.max_count('e'), Some(2));
+            assert_eq!(constraints.min_count('r'), 1);
+            assert_eq!(constraints.max_count('r'), None);
+            assert_eq!(constraints.max_count('i'), Some(0));
+            assert_eq!(
+                constraints.known_positions(),
+                &[None, None, None, None, Some('e')]
+            );
+            assert_eq!(
+                constraints.forbidden_positions('e'),
+                Some(&Bitmask::from_positions(&[0, 1]))
+            );
+
+            assert!(constraints.matches(&solution));
+            assert!(constraints.matches(&WordProcessor::new("where")));
+            assert!(!constraints.matches(&WordProcessor::new("three")));
+            assert!(!constraints.matches(&WordProcessor::new("eerie")));
+        }
+
+        #[test]
+        fn test_gray_letter_not_capped() {
+            let guess = WordProcessor::new("eerie");
+            let mut constraints = LetterConstraints::new();
+            constraints.add(&guess, &"y?bbg".parse().unwrap());
+
+            assert_eq!(constraints.min_count('e'), 2);
+            assert_eq!(constraints.max_count('e'), None);
+            assert_eq!(constraints.max_count('r'), Some(0));
+            assert!(constraints.matches(&WordProcessor::new("geese")));
+        }
+
+        #[test]
+        fn test_prefilter_agrees() {
+            let word_bank: Vec<WordProcessor> = include_str!("word_bank.txt")
+                .lines()
+                .map(WordProcessor::new)
+                .collect();
+            let mut word_suggestor = WordSuggestor::new(word_bank.clone());
+            word_suggestor
+                .add_guess("eerie", "ybybg".parse().unwrap())
+                .unwrap();
+
+            let constraints = word_suggestor.letter_constraints();
+            let guess = WordProcessor::new("eerie");
+            let clues: Clues = "ybybg".parse().unwrap();
+            word_bank
+                .iter()
+                .filter(|solution| {
+                    WordClues::from_solution(&guess, solution).get_colors() == &clues
+                })
+                .for_each(|solution| assert!(constraints.matches(solution)));
+        }
+    }
+
+    mod game_state {
+        use super::*;
+
+        fn word_bank() -> Vec<WordProcessor<'static>> {
+            include_str!("word_bank.txt")
+                .lines()
+                .map(WordProcessor::new)
+                .collect()
+        }
+
+        fn solutions<'a>(word_suggestor: &'a WordSuggestor) -> Vec<&'a str> {
+            word_suggestor
+                .possible_solutions()
+                .into_iter()
+                .map(|solution| solution.word)
+                .collect()
+        }
+
+        #[test]
+        fn test_partial_clues() {
+            let solutions = |clues: &str| {
+                let mut word_suggestor = WordSuggestor::new(word_bank());
+                word_suggestor
+                    .add_guess("serai", clues.parse().unwrap())
+                    .unwrap();
+                solutions(&word_suggestor)
+                    .into_iter()
+                    .map(str::to_owned)
+                    .collect::<Vec<String>>()
+            };
+            let full = solutions("bygbb");
+            let partial = solutions("byg??");
+
+            assert!(partial.contains(&"forge".to_owned()));
+            assert!(full.iter().all(|word| partial.contains(word)));
+            assert!(partial.len() > full.len());
+            assert!(partial.len() < word_bank().len());
+            assert!(partial.iter().all(|word| word.as_bytes()[2] == b'r'));
+        }
+
+        #[test]
+        fn test_round_trip() {
+            let solution = WordProcessor::new("forge");
+            let mut word_suggestor = WordSuggestor::new(word_bank());
+            for guess in ["serai", "mount"] {
+                let clues = WordClues::from_solution(&WordProcessor::new(guess), &solution).into();
+                word_suggestor.add_guess(guess, clues).unwrap();
+            }
+
+            let saved = serde_json::to_string(&word_suggestor.game_state()).unwrap();
+            let state: GameState = serde_json::from_str(&saved).unwrap();
+            assert_eq!(state, word_suggestor.game_state());
+
+            let reloaded = WordSuggestor::from_game_state(word_bank(), &state).unwrap();
+            assert!(solutions(&word_suggestor).contains(&"forge"));
+            assert_eq!(solutions(&reloaded), solutions(&word_suggestor));
+        }
+
+        #[tokio::test]
+        async fn test_reload_from_database() {
+            use entity::game;
+            use migration::{Migrator, MigratorTrait};
+
+            let db = sea_orm::Database::connect("sqlite::memory:").await.unwrap();
+            Migrator::up(&db, None).await.unwrap();
+
+            let solution = WordProcessor::new("forge");
+            let mut word_suggestor = WordSuggestor::new(word_bank());
+            let game = game::Entity::create(&db).await.unwrap();
+            for guess in ["serai", "mount"] {
+                let clues: Clues =
+                    WordClues::from_solution(&WordProcessor::new(guess), &solution).into();
+                game.add_guess(&db, guess, &clues.to_string())
+                    .await
+                    .unwrap();
+                word_suggestor.add_guess(guess, clues).unwrap();
+            }
+
+            let state = GameState::try_from(game.history(&db).await.unwrap()).unwrap();
+            let reloaded = WordSuggestor::from_game_state(word_bank(), &state).unwrap();
+            assert_eq!(state, word_suggestor.game_state());
+            assert_eq!(solutions(&reloaded), solutions(&word_suggestor));
+        }
+
+        #[tokio::test]
+        async fn test_reload_mismatched_clues() {
+            use entity::game;
+            use migration::{Migrator, MigratorTrait};
+
+            let db = sea_orm::Database::connect("sqlite::memory:").await.unwrap();
+            Migrator::up(&db, None).await.unwrap();
+
+            let game = game::Entity::create(&db).await.unwrap();
+            game.add_guess(&db, "serai", "bbbb").await.unwrap();
+
+            assert!(GameState::try_from(game.history(&db).await.unwrap()).is_err());
+        }
+
+        #[test]
+        fn test_separate_answers() {
+            // The answers differ only in their first letter, which no answer can narrow down
+            let answers = ["bills", "fills", "hills", "mills"];
+            let mut word_bank = answers.to_vec();
+            word_bank.push("bumph");
+
+            let mut word_suggestor =
+                WordSuggestor::new(word_bank.into_iter().map(WordProcessor::new).collect())
+                    .with_answers(answers.map(WordProcessor::new).to_vec());
+            word_suggestor
+                .add_guess("serai", "ybbby".parse().unwrap())
+                .unwrap();
+
+            assert_eq!(solutions(&word_suggestor), answers);
+            assert_eq!(
+                word_suggestor.suggest_word(&LowestMaxBucketRanker::new(), false),
+                "bumph"
+            );
+        }
+
+        #[test]
+        fn test_hard_mode() {
+            let solution = WordProcessor::new("forge");
+            let mut word_suggestor = WordSuggestor::new(word_bank()).with_hard_mode(true);
+            let clues = WordClues::from_solution(&WordProcessor::new("serai"), &solution).into();
+            word_suggestor.add_guess("serai", clues).unwrap();
+
+            let suggestion = word_suggestor.suggest_word(&LowestMaxBucketRanker::new(), false);
+            let suggestion = WordProcessor::new(&suggestion);
+            assert!(word_suggestor.letter_constraints().matches(&suggestion));
+            assert!(solutions(&word_suggestor).contains(&suggestion.word()));
+        }
+    }
+
+    mod word_length {
+        use super::*;
+
+        const BANK: [&str; 8] = [
+            "lamp", "ramp", "damp", "lime", "mile", "pale", "leap", "plum",
+        ];
+
+        #[test]
+        fn test_four_letter_clues() {
+            assert_eq!(
+                Clues::from(WordClues::from_solution(
+                    &WordProcessor::new("ramp"),
+                    &WordProcessor::new("pale")
+                )),
+                Clues::for_word("ramp", "bgby").unwrap()
+            );
+            assert!(Clues::for_word("lamp", "bgby").is_ok());
+            assert!(Clues::for_word("lamp", "bgbyb").is_err());
+        }
+
+        #[test]
+        fn test_solves_four_letter_puzzle() {
+            let solution = WordProcessor::new("leap");
+            let mut word_suggestor = WordSuggestor::new(BANK.map(WordProcessor::new).to_vec());
+
+            let mut guesses = vec![];
+            while guesses.last() != Some(&"leap".to_owned()) {
+                let guess = word_suggestor.suggest_word(&LowestMaxBucketRanker::new(), false);
+                assert!(BANK.contains(&guess.as_str()));
+                assert!(guesses.len() < BANK.len());
+                guesses.push(guess);
+
+                let guess = BANK.iter().find(|&&word| word == guesses.last().unwrap());
+                let guess = WordProcessor::new(guess.unwrap());
+                let clues = WordClues::from_solution(&guess, &solution).into();
+                word_suggestor.add_guess(guess.word(), clues).unwrap();
+            }
+        }
+    }
+
+    mod json {
+        use super::*;
+
+        const BANK: &str = r#"["forge", "serai", "mount"]"#;
+
+        #[test]
+        fn test_suggest_from_json() {
+            // Every word tells the other two apart, so the tie goes alphabetically
+            assert_eq!(suggest_from_json(BANK, "[]").unwrap(), "forge");
+            assert_eq!(
+                suggest_from_json(BANK, r#"[["serai", "bygbb"]]"#).unwrap(),
+                "forge"
+            );
+        }
+
+        #[test]
+        fn test_suggest_from_json_errors() {
+            assert!(matches!(
+                suggest_from_json("forge", "[]"),
+                Err(PuzzleError::Parse { .. })
+            ));
+            assert!(matches!(
+                suggest_from_json(BANK, r#"[["serai", "bxgbb"]]"#),
+                Err(PuzzleError::Parse { .. })
+            ));
+            assert!(matches!(
+                suggest_from_json(BANK, r#"[["serai", "bygb"]]"#),
+                Err(PuzzleError::Parse { .. })
+            ));
+        }
+    }
+    mod properties {
+        use super::*;
+        use proptest::prelude::*;
+
+        // A small alphabet so that repeated letters come up often
+        const WORD: &str = "[a-f]{5}";
+
+        fn clues(guess: &str, solution: &str) -> Clues {
+            WordClues::from_solution(&WordProcessor::new(guess), &WordProcessor::new(solution))
+                .into()
+        }
+
+        proptest! {
+            #[test]
+            fn test_self_is_all_green(word in WORD) {
+                prop_assert_eq!(clues(&word, &word), Clues(vec![Color::GREEN; 5]));
+            }
+
+            #[test]
+            fn test_hits_bounded_by_solution(guess in WORD, solution in WORD) {
+                let clues = clues(&guess, &solution);
+
+                for c in guess.chars() {
+                    let hits = guess
+                        .chars()
+                        .zip(clues.0.iter())
+                        .filter(|&(g, &color)| g == c && color != Color::BLACK)
+                        .count();
+                    prop_assert!(hits <= solution.chars().filter(|&s| s == c).count());
+                }
+            }
+
+            #[test]
+            fn test_regenerated_clue_is_stable(guess in WORD, solution in WORD) {
+                let first = clues(&guess, &solution);
+                let second = clues(&guess, &solution);
+                prop_assert_eq!(&first, &second);
+
+                let mut word_suggestor = WordSuggestor::new(vec![WordProcessor::new(&solution)]);
+                word_suggestor.add_guess(&guess, first).unwrap();
+                let solutions: Vec<&str> = word_suggestor
+                    .possible_solutions()
+                    .into_iter()
+                    .map(|solution| solution.word)
+                    .collect();
+                prop_assert_eq!(solutions, vec![solution.as_str()]);
+            }
+        }
+    }
+}